@@ -0,0 +1,136 @@
+//! Compares reading responses through `serde_json::Value` with typed envelopes deserialized from the body.
+//!
+//! Run with `cargo bench --bench deserialize`.
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    sync::atomic::{AtomicUsize, Ordering},
+    time::Instant,
+};
+
+use serde::de::DeserializeOwned;
+use serde_json::{json, Value};
+use steam_api_client::{
+    __bench::{Envelope, Games, Schema},
+    entity::{Game, GameSchema},
+};
+
+/// Allocator counting allocations and allocated bytes.
+struct CountingAlloc;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        ALLOCATED.fetch_add(layout.size(), Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        ALLOCATED.fetch_add(new_size, Ordering::Relaxed);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+const ITERATIONS: u32 = 50;
+
+fn owned_games(count: usize) -> Vec<u8> {
+    let games: Vec<Value> = (0..count)
+        .map(|i| {
+            json!({
+                "appid": 10 + i * 10,
+                "name": format!("Game number {}", i),
+                "playtime_forever": i * 7,
+                "img_icon_url": "0123456789abcdef0123456789abcdef01234567",
+                "has_community_visible_stats": true,
+                "playtime_windows_forever": i * 3,
+                "playtime_mac_forever": 0,
+                "playtime_linux_forever": i * 4,
+                "rtime_last_played": 1_700_000_000 + i,
+                "playtime_disconnected": 0
+            })
+        })
+        .collect();
+
+    serde_json::to_vec(&json!({ "response": { "game_count": count, "games": games } })).unwrap()
+}
+
+fn game_schema(count: usize) -> Vec<u8> {
+    let achievements: Vec<Value> = (0..count)
+        .map(|i| {
+            json!({
+                "name": format!("ACH_{}", i),
+                "defaultvalue": 0,
+                "displayName": format!("Achievement {}", i),
+                "hidden": i % 2,
+                "description": "Complete the chapter without taking any damage on the hardest difficulty",
+                "icon": format!("https://steamcdn-a.akamaihd.net/steamcommunity/public/images/apps/400/{}.jpg", i),
+                "icongray": format!("https://steamcdn-a.akamaihd.net/steamcommunity/public/images/apps/400/{}_gray.jpg", i)
+            })
+        })
+        .collect();
+    let stats: Vec<Value> = (0..count / 2)
+        .map(|i| json!({ "name": format!("STAT_{}", i), "defaultvalue": 0, "displayName": format!("Stat {}", i) }))
+        .collect();
+
+    serde_json::to_vec(&json!({
+        "game": {
+            "gameName": "Benchmark",
+            "gameVersion": "12",
+            "availableGameStats": { "achievements": achievements, "stats": stats }
+        }
+    }))
+    .unwrap()
+}
+
+/// Previous approach, parsing into [Value] and cloning the selected part.
+fn via_value<T: DeserializeOwned>(body: &[u8], path: &[&str]) -> T {
+    let value: Value = serde_json::from_slice(body).unwrap();
+    let selected = path.iter().fold(&value, |value, key| &value[*key]);
+    serde_json::from_value(selected.clone()).unwrap()
+}
+
+fn measure<T>(name: &str, mut f: impl FnMut() -> T) {
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let allocated = ALLOCATED.load(Ordering::Relaxed);
+    let start = Instant::now();
+
+    for _ in 0..ITERATIONS {
+        std::hint::black_box(f());
+    }
+
+    let elapsed = start.elapsed() / ITERATIONS;
+    let allocations = (ALLOCATIONS.load(Ordering::Relaxed) - allocations) / ITERATIONS as usize;
+    let allocated = (ALLOCATED.load(Ordering::Relaxed) - allocated) / ITERATIONS as usize;
+
+    println!(
+        "{:<28} {:>10.2?} {:>10} allocations {:>12} bytes",
+        name, elapsed, allocations, allocated
+    );
+}
+
+fn main() {
+    let games = owned_games(5_000);
+    println!("GetOwnedGames, 5000 games, {} bytes", games.len());
+    measure("value + clone", || via_value::<Vec<Game>>(&games, &["response", "games"]));
+    measure("typed envelope", || {
+        serde_json::from_slice::<Envelope<Games<Game>>>(&games).unwrap().response.visibility()
+    });
+
+    let schema = game_schema(1_000);
+    println!("GetSchemaForGame, 1000 achievements, {} bytes", schema.len());
+    measure("value + clone", || via_value::<GameSchema>(&schema, &["game"]));
+    measure("typed envelope", || {
+        serde_json::from_slice::<Schema>(&schema).unwrap().into_game_schema().ok().unwrap()
+    });
+}
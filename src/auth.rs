@@ -0,0 +1,152 @@
+use std::fmt::{Debug, Formatter};
+
+use reqwest::Url;
+
+/// Query and form parameters carrying client credentials.
+pub(crate) const CREDENTIAL_PARAMS: [&str; 2] = ["key", "access_token"];
+
+/// Query and form parameters never included in logs and errors.
+const SECRET_PARAMS: [&str; 3] = ["key", "access_token", "trade_offer_access_token"];
+
+/// Replaces credentials in query of given url.
+pub(crate) fn redact_url(url: &mut Url) {
+    if !url.query_pairs().any(|(name, _)| is_secret(&name)) {
+        return;
+    }
+
+    let pairs: Vec<(String, String)> = url.query_pairs().into_owned().collect();
+    url.query_pairs_mut().clear().extend_pairs(pairs.iter().map(|(name, value)| {
+        match is_secret(name) {
+            true => (name.as_str(), "REDACTED"),
+            false => (name.as_str(), value.as_str()),
+        }
+    }));
+}
+
+/// Returns `true` for parameters carrying credentials or trade offer tokens.
+pub(crate) fn is_secret(param: &str) -> bool {
+    SECRET_PARAMS.contains(&param)
+}
+
+/// Credentials used for Steam API requests.
+///
+/// Each endpoint uses the credential it needs, endpoints accepting both prefer the api key.
+#[derive(Clone, Default, PartialEq, Eq)]
+pub enum Auth {
+    /// No credentials, only public endpoints can be used
+    #[default]
+    None,
+    /// Web API key
+    ApiKey(String),
+    /// User access token
+    AccessToken(String),
+    /// Both Web API key and user access token
+    Both {
+        /// Web API key
+        api_key: String,
+        /// User access token
+        access_token: String,
+    },
+}
+
+impl Auth {
+    /// Returns api key, if set.
+    pub fn api_key(&self) -> Option<&str> {
+        match self {
+            Self::ApiKey(key) | Self::Both { api_key: key, .. } => Some(key),
+            _ => None,
+        }
+    }
+
+    /// Returns access token, if set.
+    pub fn access_token(&self) -> Option<&str> {
+        match self {
+            Self::AccessToken(token) | Self::Both { access_token: token, .. } => Some(token),
+            _ => None,
+        }
+    }
+
+    /// Returns credentials with api key replaced, keeping the access token.
+    pub fn with_api_key(self, api_key: String) -> Self {
+        match self.access_token() {
+            Some(token) => Self::Both {
+                api_key,
+                access_token: token.to_owned(),
+            },
+            None => Self::ApiKey(api_key),
+        }
+    }
+
+    /// Returns credentials with access token replaced, keeping the api key.
+    pub fn with_access_token(self, access_token: String) -> Self {
+        match self.api_key() {
+            Some(key) => Self::Both {
+                api_key: key.to_owned(),
+                access_token,
+            },
+            None => Self::AccessToken(access_token),
+        }
+    }
+}
+
+impl Debug for Auth {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        // never print the credentials themselves
+        match self {
+            Self::None => f.write_str("None"),
+            Self::ApiKey(_) => f.write_str("ApiKey(..)"),
+            Self::AccessToken(_) => f.write_str("AccessToken(..)"),
+            Self::Both { .. } => f.write_str("Both(..)"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use reqwest::Url;
+
+    use super::redact_url;
+    use crate::{RawCall, SasError};
+
+    #[test]
+    fn redacts_credentials_in_query() {
+        let mut url = Url::parse("https://api.steampowered.com/I/M/v1/?key=SECRET&steamid=1&access_token=TOKEN").unwrap();
+        redact_url(&mut url);
+
+        assert_eq!(url.query(), Some("key=REDACTED&steamid=1&access_token=REDACTED"));
+    }
+
+    #[test]
+    fn keeps_url_without_credentials() {
+        let mut url = Url::parse("https://api.steampowered.com/I/M/v1/?steamid=1").unwrap();
+        redact_url(&mut url);
+
+        assert_eq!(url.as_str(), "https://api.steampowered.com/I/M/v1/?steamid=1");
+    }
+
+    #[tokio::test]
+    async fn redacts_credentials_in_network_errors() {
+        // nothing listens on port 9, so the request fails with the url in the error
+        let url = "http://127.0.0.1:9/I/M/v1/?key=SECRET&access_token=TOKEN&trade_offer_access_token=TRADE";
+        let error: SasError = reqwest::get(url).await.unwrap_err().into();
+
+        let printed = format!("{} {:?}", error, error);
+        assert!(!printed.contains("SECRET"));
+        assert!(!printed.contains("TOKEN"));
+        assert!(!printed.contains("TRADE"));
+        assert!(printed.contains("key=REDACTED"));
+    }
+
+    #[test]
+    fn hides_credentials_in_raw_call_debug() {
+        let call = RawCall::get("I", "M", 1)
+            .param("key", "SECRET")
+            .param("access_token", "TOKEN")
+            .param("steamid", "1");
+
+        let printed = format!("{:?}", call);
+        assert!(!printed.contains("SECRET"));
+        assert!(!printed.contains("TOKEN"));
+        assert!(printed.contains("(\"steamid\", \"1\")"));
+    }
+}
@@ -1,10 +1,42 @@
-use serde::{Deserialize, Deserializer, Serialize};
+use std::collections::BTreeMap;
 
+use serde::{de::Error, Deserialize, Deserializer, Serialize};
+
+mod api;
+mod economy;
+mod family;
 mod game;
+mod group;
+mod image;
+mod leaderboard;
 mod player;
+mod profile;
+mod publisher;
+mod server;
+mod store;
+mod trade;
+mod wishlist;
 
-pub use game::{Game, RecentGame};
-pub use player::Player;
+pub use api::{ApiInterface, ApiMethod, ApiParameter, EndpointStatus, EndpointSupport, ServerInfo};
+pub use economy::{
+    AssetClass, AssetClassInfo, AssetClassProperty, AssetDescription, AssetPrice, AssetPrices, AssetTag,
+};
+pub use family::{FamilyGroup, FamilyGroupForUser, FamilyMember, FamilyRole, SharedApp};
+pub use game::{AppImage, Game, RecentGame};
+pub use group::{GroupDetails, GroupMembers, UserGroup};
+pub use image::{DownloadProgress, Image, ImageFormat, ImageSource};
+pub use leaderboard::{Leaderboard, LeaderboardEntries, LeaderboardEntry, LeaderboardRange};
+pub use player::{AvatarSize, Player, Visibility};
+pub use profile::{ProfileItem, ProfileItemsEquipped};
+pub(crate) use profile::equipped;
+pub use publisher::{AppBeta, AppOwnership, UserTicket};
+pub use server::{GameServer, ServerAtAddress, ServerFilter};
+pub use store::{AppDetails, Platforms, PriceOverview, ReleaseDate};
+pub use trade::{
+    Trade, TradeAsset, TradeHistory, TradeHistoryQuery, TradeHoldDuration, TradeHoldDurations, TradeOffer,
+    TradeOfferDetail, TradeOfferState, TradeOffers, TradeOffersQuery, TradeOffersSummary, TradeStatus, TradedAsset,
+};
+pub use wishlist::{WishlistEntry, WishlistItem};
 
 /// Game schema information
 #[derive(Clone, Serialize, Deserialize, Debug)]
@@ -80,7 +112,9 @@ pub(crate) enum PlayerStatsSchema {
         steam_id: String,
         #[serde(alias = "gameName")]
         game_name: String,
-        achievements: Vec<AchievementPlayerStatsSchema>,
+        // games without achievements leave the list out
+        #[serde(default)]
+        achievements: Option<Vec<AchievementPlayerStatsSchema>>,
     },
     Error {
         error: String,
@@ -107,3 +141,96 @@ fn num_to_bool<'de, D>(d: D) -> Result<bool, D::Error>
 {
     Deserialize::deserialize(d).map(|e: i32| e == 1)
 }
+
+/// Accepts booleans, numbers and numeric strings, as Steam is not consistent with those.
+fn any_to_bool<'de, D>(d: D) -> Result<bool, D::Error>
+    where
+        D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum AnyBool {
+        Bool(bool),
+        Num(i64),
+        Str(String),
+    }
+
+    Deserialize::deserialize(d).map(|e: AnyBool| match e {
+        AnyBool::Bool(b) => b,
+        AnyBool::Num(n) => n == 1,
+        AnyBool::Str(s) => s == "1" || s == "true",
+    })
+}
+
+/// Reads objects keyed by index (`{"0": .., "1": ..}`) as list, empty string is read as empty list.
+fn indexed_list<'de, D, T>(d: D) -> Result<Vec<T>, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: Deserialize<'de>,
+{
+    // untagged content keeps the keys as strings, so they are parsed here
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    #[allow(dead_code)]
+    enum Indexed<T> {
+        Map(BTreeMap<String, T>),
+        List(Vec<T>),
+        Empty(String),
+    }
+
+    match Deserialize::deserialize(d)? {
+        Indexed::Map(map) => {
+            let mut items = map
+                .into_iter()
+                .map(|(key, item)| match key.parse::<usize>() {
+                    Ok(index) => Ok((index, item)),
+                    Err(_) => Err(D::Error::custom(format!("invalid list index `{}`", key))),
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            items.sort_by_key(|(index, _)| *index);
+
+            Ok(items.into_iter().map(|(_, item)| item).collect())
+        }
+        Indexed::List(list) => Ok(list),
+        Indexed::Empty(_) => Ok(Vec::new()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AssetClassInfo;
+
+    fn class(descriptions: &str) -> Result<AssetClassInfo, serde_json::Error> {
+        serde_json::from_str(&format!(
+            r#"{{"classid":"195151","name":"Key","icon_url":"icon","descriptions":{}}}"#,
+            descriptions
+        ))
+    }
+
+    #[test]
+    fn indexed_list_reads_index_keyed_objects() {
+        let info = class(r#"{"0":{"type":"html","value":"a"},"1":{"type":"html","value":"b"}}"#).unwrap();
+        let values: Vec<_> = info.descriptions.iter().map(|d| d.value.as_str()).collect();
+
+        assert_eq!(values, ["a", "b"]);
+    }
+
+    #[test]
+    fn indexed_list_orders_by_numeric_index() {
+        let info = class(r#"{"10":{"value":"c"},"2":{"value":"b"},"0":{"value":"a"}}"#).unwrap();
+        let values: Vec<_> = info.descriptions.iter().map(|d| d.value.as_str()).collect();
+
+        assert_eq!(values, ["a", "b", "c"]);
+    }
+
+    #[test]
+    fn indexed_list_reads_lists_and_empty_strings() {
+        assert_eq!(class(r#"[{"value":"a"}]"#).unwrap().descriptions.len(), 1);
+        assert!(class(r#""""#).unwrap().descriptions.is_empty());
+    }
+
+    #[test]
+    fn indexed_list_rejects_non_numeric_keys() {
+        assert!(class(r#"{"first":{"value":"a"}}"#).is_err());
+    }
+}
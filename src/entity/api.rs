@@ -0,0 +1,80 @@
+use serde::{Deserialize, Serialize};
+
+/// Web API interface
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct ApiInterface {
+    /// Interface name, e.g. `ISteamUser`
+    pub name: String,
+    /// Methods of the interface
+    #[serde(default)]
+    pub methods: Vec<ApiMethod>,
+}
+
+/// Web API method
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct ApiMethod {
+    /// Method name, e.g. `GetPlayerSummaries`
+    pub name: String,
+    /// Method version
+    pub version: u32,
+    /// HTTP method, `GET` or `POST`
+    #[serde(alias = "httpmethod")]
+    pub http_method: String,
+    /// Optional description
+    pub description: Option<String>,
+    /// Method parameters
+    #[serde(default)]
+    pub parameters: Vec<ApiParameter>,
+}
+
+/// Parameter of Web API method
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct ApiParameter {
+    /// Parameter name
+    pub name: String,
+    /// Parameter type, e.g. `uint64` or `string`
+    #[serde(rename = "type")]
+    pub param_type: String,
+    /// Parameter can be omitted
+    pub optional: bool,
+    /// Optional description
+    pub description: Option<String>,
+}
+
+/// Web API server info
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct ServerInfo {
+    /// Server time as unix timestamp
+    #[serde(alias = "servertime")]
+    pub server_time: u64,
+    /// Server time as formatted string
+    #[serde(alias = "servertimestring")]
+    pub server_time_string: String,
+}
+
+/// Support of method used by this library, as reported by Steam
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct EndpointSupport {
+    /// Interface name
+    pub interface: String,
+    /// Method name
+    pub method: String,
+    /// Method version used by this library
+    pub version: u32,
+    /// Support of the method
+    pub status: EndpointStatus,
+}
+
+/// Status of method used by this library
+#[derive(Copy, Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub enum EndpointStatus {
+    /// Used version is the latest one
+    Supported,
+    /// Newer version is available
+    Outdated {
+        /// Latest available version
+        latest: u32,
+    },
+    /// Method or used version is not available for the credentials
+    Missing,
+}
@@ -0,0 +1,123 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::{any_to_bool, indexed_list};
+
+/// Identifier of asset class, optionally with its instance.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct AssetClass {
+    /// ID of the class
+    pub classid: u64,
+    /// Optional ID of the class instance
+    pub instanceid: Option<u64>,
+}
+
+/// In-game store prices for an app
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct AssetPrices {
+    /// Assets sold in the store
+    pub assets: Vec<AssetPrice>,
+    /// Store tags, mapping tag name to localized name
+    #[serde(default)]
+    pub tags: HashMap<String, String>,
+}
+
+/// Entity representing single in-game store item
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct AssetPrice {
+    /// Name of the asset
+    pub name: String,
+    /// Optional date the asset was added
+    pub date: Option<String>,
+    /// Prices in the smallest currency unit, keyed by currency code
+    pub prices: HashMap<String, u64>,
+    /// Prices before discount, keyed by currency code
+    #[serde(default)]
+    pub original_prices: HashMap<String, u64>,
+    /// ID of the asset class
+    pub classid: String,
+    /// Class properties
+    #[serde(default)]
+    pub class: Vec<AssetClassProperty>,
+    /// Store tags of the asset
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+/// Named property of asset class
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct AssetClassProperty {
+    /// Property name
+    pub name: String,
+    /// Property value
+    pub value: String,
+}
+
+/// Description of asset class
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct AssetClassInfo {
+    /// ID of the class
+    pub classid: String,
+    /// Optional ID of the class instance
+    pub instanceid: Option<String>,
+    /// Localized name
+    pub name: String,
+    /// Localized name used on community market
+    pub market_name: Option<String>,
+    /// Name used on community market
+    pub market_hash_name: Option<String>,
+    /// Icon url part
+    pub icon_url: String,
+    /// Optional large icon url part
+    pub icon_url_large: Option<String>,
+    /// Name color in hex format
+    pub name_color: Option<String>,
+    /// Background color in hex format
+    pub background_color: Option<String>,
+    /// Localized type of the item
+    #[serde(rename = "type")]
+    pub item_type: Option<String>,
+    /// Item can be traded
+    #[serde(default, deserialize_with = "any_to_bool")]
+    pub tradable: bool,
+    /// Item can be sold on community market
+    #[serde(default, deserialize_with = "any_to_bool")]
+    pub marketable: bool,
+    /// Item is a commodity
+    #[serde(default, deserialize_with = "any_to_bool")]
+    pub commodity: bool,
+    /// Description lines
+    #[serde(default, deserialize_with = "indexed_list")]
+    pub descriptions: Vec<AssetDescription>,
+    /// Item tags
+    #[serde(default, deserialize_with = "indexed_list")]
+    pub tags: Vec<AssetTag>,
+}
+
+/// Single line of asset description
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct AssetDescription {
+    /// Type of the description, usually `text` or `html`
+    #[serde(rename = "type")]
+    pub description_type: Option<String>,
+    /// Description content
+    pub value: String,
+    /// Text color in hex format
+    pub color: Option<String>,
+}
+
+/// Tag of the asset class
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct AssetTag {
+    /// Internal tag name
+    pub internal_name: String,
+    /// Localized tag name
+    pub name: String,
+    /// Internal tag category
+    pub category: String,
+    /// Localized tag category
+    pub category_name: Option<String>,
+    /// Tag color in hex format
+    pub color: Option<String>,
+}
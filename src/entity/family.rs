@@ -0,0 +1,129 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{AppId, SteamId};
+
+/// Family group membership of the player
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct FamilyGroupForUser {
+    /// ID of the family group, missing when the player is not a member
+    pub family_groupid: Option<String>,
+    /// Player is not member of any family group
+    #[serde(default)]
+    pub is_not_member_of_any_group: bool,
+    /// Timestamp of joining the latest family group
+    pub latest_time_joined: Option<u64>,
+    /// ID of the latest joined family group
+    pub latest_joined_family_groupid: Option<String>,
+    /// Role of the player in the family group
+    #[serde(default)]
+    pub role: FamilyRole,
+    /// Seconds until the player can join another family group
+    #[serde(default)]
+    pub cooldown_seconds_remaining: u32,
+    /// Details of the family group
+    pub family_group: Option<FamilyGroup>,
+}
+
+/// Family group
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct FamilyGroup {
+    /// Name of the family group
+    pub name: String,
+    /// Members of the family group
+    #[serde(default)]
+    pub members: Vec<FamilyMember>,
+    /// Number of free member slots
+    #[serde(default)]
+    pub free_spots: u32,
+    /// Country of the family group
+    #[serde(default)]
+    pub country: String,
+    /// Seconds until the free slot can be used
+    #[serde(default)]
+    pub slot_cooldown_remaining_seconds: u32,
+}
+
+/// Member of family group
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct FamilyMember {
+    /// ID of the member
+    pub steamid: SteamId,
+    /// Role of the member
+    #[serde(default)]
+    pub role: FamilyRole,
+    /// Timestamp of joining the family group
+    #[serde(default)]
+    pub time_joined: u64,
+    /// Seconds until the member can join another family group
+    #[serde(default)]
+    pub cooldown_seconds_remaining: u32,
+}
+
+/// Role of family group member
+#[derive(Copy, Clone, Serialize, Deserialize, Debug, Default, PartialEq, Eq, Hash)]
+#[serde(from = "u32", into = "u32")]
+pub enum FamilyRole {
+    /// No role
+    #[default]
+    None,
+    /// Adult member, can manage the family group
+    Adult,
+    /// Child member, restricted by adults
+    Child,
+    /// Role not known to this library
+    Unknown(u32),
+}
+
+impl From<u32> for FamilyRole {
+    fn from(value: u32) -> Self {
+        match value {
+            0 => Self::None,
+            1 => Self::Adult,
+            2 => Self::Child,
+            other => Self::Unknown(other),
+        }
+    }
+}
+
+impl From<FamilyRole> for u32 {
+    fn from(value: FamilyRole) -> Self {
+        match value {
+            FamilyRole::None => 0,
+            FamilyRole::Adult => 1,
+            FamilyRole::Child => 2,
+            FamilyRole::Unknown(other) => other,
+        }
+    }
+}
+
+/// App available in family shared library
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct SharedApp {
+    /// ID of the app
+    pub appid: AppId,
+    /// IDs of family members owning the app
+    #[serde(default)]
+    pub owner_steamids: Vec<SteamId>,
+    /// Name of the app
+    #[serde(default)]
+    pub name: String,
+    /// File name of the app capsule
+    pub capsule_filename: Option<String>,
+    /// Hash of the app icon
+    pub img_icon_hash: Option<String>,
+    /// Reason for excluding the app from sharing, 0 for shared apps
+    #[serde(default)]
+    pub exclude_reason: u32,
+    /// Timestamp of acquiring the app
+    #[serde(default)]
+    pub rt_time_acquired: u64,
+    /// Timestamp of last play
+    #[serde(default)]
+    pub rt_last_played: u64,
+    /// Total playtime of the app
+    #[serde(default)]
+    pub rt_playtime: u64,
+    /// Type of the app
+    #[serde(default)]
+    pub app_type: u32,
+}
@@ -1,6 +1,31 @@
 use serde::{Deserialize, Serialize};
 use crate::AppId;
 
+/// Image of an app, served by Steam CDN
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AppImage<'a> {
+    /// Small capsule, 231x87 px
+    SmallCapsule,
+    /// Main capsule, 616x353 px
+    MainCapsule,
+    /// Capsule used in lists, 120x45 px
+    ListCapsule,
+    /// Store page header, 460x215 px
+    Header,
+    /// Library capsule, 600x900 px
+    LibraryCapsule,
+    /// Library capsule in double resolution, 1200x1800 px
+    LibraryCapsule2x,
+    /// Library hero image, 3840x1240 px
+    LibraryHero,
+    /// Transparent logo
+    Logo,
+    /// Store page background
+    PageBackground,
+    /// Community icon, with hash from `img_icon_url` of [Game] or [RecentGame]
+    Icon(&'a str),
+}
+
 /// Entity representing game or application
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Game {
@@ -0,0 +1,174 @@
+use serde::{Deserialize, Deserializer, Serialize};
+
+use crate::SteamId;
+
+/// Offset between 32-bit group id and 64-bit group steam id.
+const GROUP_ID_BASE: u64 = 103582791429521408;
+
+/// Entity representing group membership of the player
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct UserGroup {
+    /// 32-bit ID of the group
+    pub gid: String,
+}
+
+impl UserGroup {
+    /// Returns 64-bit steam id of the group, used by community pages.
+    pub fn group_id(&self) -> Option<SteamId> {
+        self.gid.parse::<u64>().ok().map(|gid| SteamId(GROUP_ID_BASE + gid))
+    }
+}
+
+/// Page of group members
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct GroupMembers {
+    /// 64-bit ID of the group
+    #[serde(rename = "groupID64", deserialize_with = "text_id")]
+    pub group_id: SteamId,
+    /// Group details
+    #[serde(rename = "groupDetails")]
+    pub details: GroupDetails,
+    /// Total number of members
+    #[serde(rename = "memberCount")]
+    pub member_count: u32,
+    /// Total number of pages
+    #[serde(rename = "totalPages")]
+    pub total_pages: u32,
+    /// Number of this page, starting from 1
+    #[serde(rename = "currentPage")]
+    pub current_page: u32,
+    /// IDs of members on this page
+    #[serde(with = "members")]
+    pub members: Vec<SteamId>,
+}
+
+/// Details of a group
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct GroupDetails {
+    /// Group name
+    #[serde(rename = "groupName")]
+    pub name: String,
+    /// Custom part of group url
+    #[serde(rename = "groupURL")]
+    pub url: String,
+    /// Group headline
+    #[serde(default)]
+    pub headline: String,
+    /// Group summary
+    #[serde(default)]
+    pub summary: String,
+    /// Url of full size avatar
+    #[serde(rename = "avatarFull")]
+    pub avatar_full: Option<String>,
+    /// Number of members
+    #[serde(rename = "memberCount")]
+    pub member_count: u32,
+    /// Number of members online
+    #[serde(rename = "membersOnline", default)]
+    pub members_online: u32,
+    /// Number of members in game
+    #[serde(rename = "membersInGame", default)]
+    pub members_in_game: u32,
+}
+
+/// XML text is always a string, steam ids from it have to be parsed.
+fn text_id<'de, D: Deserializer<'de>>(deserializer: D) -> Result<SteamId, D::Error> {
+    let id = String::deserialize(deserializer)?;
+    id.parse().map_err(serde::de::Error::custom)
+}
+
+/// Members are listed as `<members><steamID64>..</steamID64>..</members>`.
+mod members {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use crate::SteamId;
+
+    #[derive(Serialize, Deserialize)]
+    struct Members {
+        #[serde(rename = "steamID64", default)]
+        ids: Vec<String>,
+    }
+
+    pub fn serialize<S: Serializer>(ids: &[SteamId], serializer: S) -> Result<S::Ok, S::Error> {
+        let ids = ids.iter().map(|id| id.to_string()).collect();
+        Members { ids }.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<SteamId>, D::Error> {
+        Members::deserialize(deserializer)?
+            .ids
+            .iter()
+            .map(|id| id.parse().map_err(serde::de::Error::custom))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GroupMembers;
+    use crate::SteamId;
+
+    /// Page of `memberslistxml` response with given members.
+    fn members_page(members: &[&str]) -> String {
+        let members: String = members.iter().map(|id| format!("<steamID64>{}</steamID64>\n", id)).collect();
+
+        format!(
+            r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?><memberList>
+<groupID64>103582791429521412</groupID64>
+<groupDetails>
+<groupName><![CDATA[Valve]]></groupName>
+<groupURL><![CDATA[Valve]]></groupURL>
+<headline><![CDATA[]]></headline>
+<summary><![CDATA[No information given.]]></summary>
+<avatarIcon><![CDATA[https://avatars.steamstatic.com/a.jpg]]></avatarIcon>
+<avatarMedium><![CDATA[https://avatars.steamstatic.com/a_medium.jpg]]></avatarMedium>
+<avatarFull><![CDATA[https://avatars.steamstatic.com/a_full.jpg]]></avatarFull>
+<memberCount>2513</memberCount>
+<membersInChat>0</membersInChat>
+<membersInGame>31</membersInGame>
+<membersOnline>302</membersOnline>
+</groupDetails>
+<memberCount>2513</memberCount>
+<totalPages>3</totalPages>
+<currentPage>2</currentPage>
+<startingMember>1000</startingMember>
+<nextPageLink><![CDATA[https://steamcommunity.com/gid/103582791429521412/memberslistxml/?xml=1&p=3]]></nextPageLink>
+<members>
+{}</members>
+</memberList>"#,
+            members
+        )
+    }
+
+    #[test]
+    fn reads_single_member() {
+        let page: GroupMembers = quick_xml::de::from_str(&members_page(&["76561197960265731"])).unwrap();
+
+        assert_eq!(page.members, [SteamId(76561197960265731)]);
+    }
+
+    #[test]
+    fn reads_several_members() {
+        let xml = members_page(&["76561197960265731", "76561197960265733", "76561197960265738"]);
+        let page: GroupMembers = quick_xml::de::from_str(&xml).unwrap();
+
+        assert_eq!(
+            page.members,
+            [SteamId(76561197960265731), SteamId(76561197960265733), SteamId(76561197960265738)]
+        );
+    }
+
+    #[test]
+    fn reads_group_details_and_paging() {
+        let page: GroupMembers = quick_xml::de::from_str(&members_page(&["76561197960265731"])).unwrap();
+
+        assert_eq!(page.group_id, SteamId(103582791429521412));
+        assert_eq!(page.details.name, "Valve");
+        assert_eq!(page.details.headline, "");
+        assert_eq!(page.details.summary, "No information given.");
+        assert_eq!(page.details.members_online, 302);
+        assert_eq!(page.member_count, 2513);
+        assert_eq!(page.total_pages, 3);
+        assert_eq!(page.current_page, 2);
+    }
+}
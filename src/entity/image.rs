@@ -0,0 +1,123 @@
+use bytes::Bytes;
+
+use crate::AppId;
+
+use super::{AchievementSchema, AppImage, AvatarSize, Player};
+
+/// Image which can be downloaded with [SteamClient::download_image](crate::SteamClient::download_image)
+#[derive(Copy, Clone, Debug)]
+pub enum ImageSource<'a> {
+    /// Image of an app
+    App {
+        /// ID of the app
+        appid: AppId,
+        /// Kind of the image
+        image: AppImage<'a>,
+    },
+    /// Achievement icon
+    Achievement {
+        /// Achievement from game schema
+        achievement: &'a AchievementSchema,
+        /// Icon of achieved achievement, gray icon otherwise
+        achieved: bool,
+    },
+    /// Avatar of a player
+    Avatar {
+        /// Player from player summary
+        player: &'a Player,
+        /// Size of the avatar
+        size: AvatarSize,
+    },
+}
+
+/// Downloaded image
+#[derive(Clone, Debug)]
+pub struct Image {
+    /// Image data
+    pub bytes: Bytes,
+    /// Content type reported by the server, e.g. `image/jpeg`
+    pub content_type: Option<String>,
+    /// Format detected from the data, `None` for formats not known to this library
+    pub format: Option<ImageFormat>,
+}
+
+/// Progress of image download
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct DownloadProgress {
+    /// Number of bytes downloaded so far
+    pub downloaded: u64,
+    /// Size of the image, when reported by the server
+    pub total: Option<u64>,
+}
+
+/// Format of image data
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ImageFormat {
+    /// JPEG image
+    Jpeg,
+    /// PNG image
+    Png,
+    /// WebP image
+    Webp,
+    /// GIF image
+    Gif,
+}
+
+impl ImageFormat {
+    /// Detects format from the signature at the start of image data.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use steam_api_client::entity::ImageFormat;
+    ///
+    /// assert_eq!(ImageFormat::detect(b"\x89PNG\r\n\x1a\n"), Some(ImageFormat::Png));
+    /// assert_eq!(ImageFormat::detect(b"<html>"), None);
+    /// ```
+    pub fn detect(data: &[u8]) -> Option<Self> {
+        match data {
+            [0xFF, 0xD8, 0xFF, ..] => Some(Self::Jpeg),
+            [0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1A, b'\n', ..] => Some(Self::Png),
+            [b'R', b'I', b'F', b'F', _, _, _, _, b'W', b'E', b'B', b'P', ..] => Some(Self::Webp),
+            [b'G', b'I', b'F', b'8', ..] => Some(Self::Gif),
+            _ => None,
+        }
+    }
+
+    /// Returns MIME type of the format.
+    pub fn mime_type(&self) -> &'static str {
+        match self {
+            Self::Jpeg => "image/jpeg",
+            Self::Png => "image/png",
+            Self::Webp => "image/webp",
+            Self::Gif => "image/gif",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ImageFormat;
+
+    #[test]
+    fn detects_known_signatures() {
+        assert_eq!(ImageFormat::detect(b"\xFF\xD8\xFF\xE0\0\x10JFIF"), Some(ImageFormat::Jpeg));
+        assert_eq!(ImageFormat::detect(b"\x89PNG\r\n\x1a\n"), Some(ImageFormat::Png));
+        assert_eq!(ImageFormat::detect(b"RIFF\x24\0\0\0WEBPVP8 "), Some(ImageFormat::Webp));
+        assert_eq!(ImageFormat::detect(b"GIF89a"), Some(ImageFormat::Gif));
+    }
+
+    #[test]
+    fn ignores_unknown_magic_bytes() {
+        for data in [
+            &b""[..],
+            b"BM\0\0\0\0",
+            b"\xFF\xD8",
+            b"\x89PNG\r\n",
+            b"RIFF\x24\0\0\0WAVEfmt ",
+            b"GIF7",
+            b"{\"error\":\"not found\"}",
+        ] {
+            assert_eq!(ImageFormat::detect(data), None, "{:?}", data);
+        }
+    }
+}
@@ -0,0 +1,168 @@
+use serde::{de::Error, Deserialize, Deserializer, Serialize};
+
+use crate::SteamId;
+
+/// Entity representing game leaderboard
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct Leaderboard {
+    /// ID of the leaderboard
+    pub id: u64,
+    /// Name used throughout API
+    pub name: String,
+    /// Localized name
+    pub display_name: Option<String>,
+    /// Number of entries
+    #[serde(default)]
+    pub entries: u32,
+    /// Sort method, `Ascending` or `Descending`
+    #[serde(alias = "sortmethod")]
+    pub sort_method: String,
+    /// Display type, e.g. `Numeric` or `TimeSeconds`
+    #[serde(alias = "displaytype")]
+    pub display_type: String,
+    /// Only trusted writes are allowed
+    #[serde(alias = "onlytrustedwrites", default)]
+    pub only_trusted_writes: bool,
+    /// Only friends' entries can be read
+    #[serde(alias = "onlyfriendsreads", default)]
+    pub only_friends_reads: bool,
+}
+
+/// Which entries of leaderboard to return
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum LeaderboardRange<'a> {
+    /// Entries by rank, both ends inclusive, starting from 1
+    Global {
+        /// First rank
+        start: u32,
+        /// Last rank
+        end: u32,
+    },
+    /// Entries around given player
+    AroundUser {
+        /// ID of the player
+        steam_id: &'a str,
+        /// Number of entries before the player
+        before: u32,
+        /// Number of entries after the player
+        after: u32,
+    },
+    /// Entries of the player's friends
+    Friends {
+        /// ID of the player
+        steam_id: &'a str,
+    },
+}
+
+/// Page of leaderboard entries
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct LeaderboardEntries {
+    /// Total number of entries in the leaderboard
+    #[serde(alias = "totalLeaderBoardEntryCount", default)]
+    pub total: u32,
+    /// Requested entries
+    #[serde(alias = "leaderboardEntries", default)]
+    pub entries: Vec<LeaderboardEntry>,
+}
+
+/// Single leaderboard entry
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct LeaderboardEntry {
+    /// ID of the player
+    #[serde(alias = "steamID")]
+    pub steam_id: SteamId,
+    /// Score
+    pub score: i32,
+    /// Global rank, starting from 1
+    pub rank: u32,
+    /// Optional ID of attached user generated content
+    #[serde(default, deserialize_with = "ugc_id")]
+    pub ugcid: Option<u64>,
+    /// Game specific details
+    #[serde(alias = "detailData", default, deserialize_with = "hex_to_bytes")]
+    pub details: Vec<u8>,
+}
+
+/// Steam uses `-1` for missing content.
+fn ugc_id<'de, D>(d: D) -> Result<Option<u64>, D::Error>
+    where
+        D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum StrOrNum {
+        Str(String),
+        Num(i64),
+    }
+
+    let id: Option<StrOrNum> = Deserialize::deserialize(d)?;
+    Ok(match id {
+        Some(StrOrNum::Str(id)) => id.parse().ok(),
+        Some(StrOrNum::Num(id)) => u64::try_from(id).ok(),
+        None => None,
+    })
+}
+
+fn hex_to_bytes<'de, D>(d: D) -> Result<Vec<u8>, D::Error>
+    where
+        D: Deserializer<'de>,
+{
+    let hex: Option<String> = Deserialize::deserialize(d)?;
+    let hex = hex.unwrap_or_default();
+
+    hex.as_bytes()
+        .chunks(2)
+        .map(|pair| match pair {
+            [high, low] => hex_digit(*high)
+                .zip(hex_digit(*low))
+                .map(|(high, low)| high << 4 | low)
+                .ok_or_else(|| D::Error::custom("invalid hex string")),
+            _ => Err(D::Error::custom("odd length of hex string")),
+        })
+        .collect()
+}
+
+fn hex_digit(digit: u8) -> Option<u8> {
+    char::from(digit).to_digit(16).map(|digit| digit as u8)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LeaderboardEntry;
+
+    fn entry(fields: &str) -> Result<LeaderboardEntry, serde_json::Error> {
+        serde_json::from_str(&format!(r#"{{"steamID":"76561197960265731","score":120,"rank":1{}}}"#, fields))
+    }
+
+    #[test]
+    fn decodes_detail_data() {
+        assert_eq!(entry(r#","detailData":"00ff1A""#).unwrap().details, [0x00, 0xff, 0x1a]);
+        assert!(entry(r#","detailData":"""#).unwrap().details.is_empty());
+        assert!(entry("").unwrap().details.is_empty());
+    }
+
+    #[test]
+    fn rejects_odd_length_detail_data() {
+        let error = entry(r#","detailData":"0ff""#).unwrap_err();
+
+        assert!(error.to_string().contains("odd length"), "{}", error);
+    }
+
+    #[test]
+    fn rejects_invalid_hex_digits() {
+        for details in ["0g", "+1", "zz00", "é00"] {
+            let error = entry(&format!(r#","detailData":"{}""#, details)).unwrap_err();
+
+            assert!(error.to_string().contains("invalid hex"), "{}: {}", details, error);
+        }
+    }
+
+    #[test]
+    fn maps_missing_ugc_to_none() {
+        assert_eq!(entry(r#","ugcid":"-1""#).unwrap().ugcid, None);
+        assert_eq!(entry(r#","ugcid":-1"#).unwrap().ugcid, None);
+        assert_eq!(entry("").unwrap().ugcid, None);
+        assert_eq!(entry(r#","ugcid":"1234567890123""#).unwrap().ugcid, Some(1234567890123));
+        assert_eq!(entry(r#","ugcid":42"#).unwrap().ugcid, Some(42));
+    }
+}
@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
 
+use crate::error::SasError;
+
 /// Entity representing the player
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Player {
@@ -9,6 +11,91 @@ pub struct Player {
     pub profileurl: String,
     /// Public username
     pub personaname: String,
+    /// Url of 32x32 px profile picture
+    #[serde(default)]
+    pub avatar: String,
+    /// Url of 64x64 px profile picture
+    #[serde(default)]
+    pub avatarmedium: String,
     /// Url of profile picture
     pub avatarfull: String,
+    /// Visibility of the profile to the client, `3` for public profiles
+    #[serde(default)]
+    pub communityvisibilitystate: u8,
+}
+
+impl Player {
+    /// Returns `true` when details of the profile, e.g. games, are visible to the client.
+    pub fn is_public(&self) -> bool {
+        self.communityvisibilitystate == 3
+    }
+
+    /// Returns url of profile picture of given size.
+    pub fn avatar_url(&self, size: AvatarSize) -> &str {
+        match size {
+            AvatarSize::Small => &self.avatar,
+            AvatarSize::Medium => &self.avatarmedium,
+            AvatarSize::Full => &self.avatarfull,
+        }
+    }
+}
+
+/// Player data, which may be hidden by privacy settings of the profile.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Visibility<T> {
+    /// Data is visible to the client
+    Public(T),
+    /// Data is hidden by privacy settings
+    Private,
+}
+
+impl<T> Visibility<T> {
+    /// Returns `true` when the data is hidden.
+    pub fn is_private(&self) -> bool {
+        matches!(self, Self::Private)
+    }
+
+    /// Returns the data, `None` when it is hidden.
+    pub fn public(self) -> Option<T> {
+        match self {
+            Self::Public(data) => Some(data),
+            Self::Private => None,
+        }
+    }
+
+    /// Returns the data, failing with [SasError::PrivateProfile] when it is hidden.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use steam_api_client::{entity::Visibility, SasError};
+    ///
+    /// assert_eq!(Visibility::Public(5).into_result().unwrap(), 5);
+    /// assert!(matches!(Visibility::<u32>::Private.into_result(), Err(SasError::PrivateProfile { .. })));
+    /// ```
+    pub fn into_result(self) -> Result<T, SasError> {
+        match self {
+            Self::Public(data) => Ok(data),
+            Self::Private => Err(SasError::PrivateProfile { diagnostics: None }),
+        }
+    }
+
+    /// Maps the data, keeping the visibility.
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> Visibility<U> {
+        match self {
+            Self::Public(data) => Visibility::Public(f(data)),
+            Self::Private => Visibility::Private,
+        }
+    }
+}
+
+/// Size of profile picture
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum AvatarSize {
+    /// 32x32 px
+    Small,
+    /// 64x64 px
+    Medium,
+    /// 184x184 px
+    #[default]
+    Full,
 }
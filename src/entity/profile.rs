@@ -0,0 +1,136 @@
+use serde::{de::Error, Deserialize, Deserializer, Serialize};
+
+use crate::AppId;
+
+/// Base url of community images, item paths are relative to it.
+const COMMUNITY_IMAGE_URL: &str = "https://cdn.akamai.steamstatic.com/steamcommunity/public/images";
+
+/// Profile customization items equipped by the player
+#[derive(Clone, Serialize, Deserialize, Debug, Default)]
+pub struct ProfileItemsEquipped {
+    /// Profile background
+    #[serde(default, deserialize_with = "equipped")]
+    pub profile_background: Option<ProfileItem>,
+    /// Mini profile background
+    #[serde(default, deserialize_with = "equipped")]
+    pub mini_profile_background: Option<ProfileItem>,
+    /// Avatar frame
+    #[serde(default, deserialize_with = "equipped")]
+    pub avatar_frame: Option<ProfileItem>,
+    /// Animated avatar
+    #[serde(default, deserialize_with = "equipped")]
+    pub animated_avatar: Option<ProfileItem>,
+    /// Profile modifier, e.g. seasonal theme
+    #[serde(default, deserialize_with = "equipped")]
+    pub profile_modifier: Option<ProfileItem>,
+}
+
+/// Profile customization item
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct ProfileItem {
+    /// ID of the item
+    pub communityitemid: String,
+    /// Path of small image
+    pub image_small: Option<String>,
+    /// Path of large image
+    pub image_large: Option<String>,
+    /// Internal name
+    #[serde(default)]
+    pub name: String,
+    /// Displayed title
+    #[serde(default)]
+    pub item_title: String,
+    /// Displayed description
+    #[serde(default)]
+    pub item_description: String,
+    /// ID of the app the item belongs to
+    pub appid: AppId,
+    /// Type of the item
+    #[serde(default)]
+    pub item_type: u32,
+    /// Class of the item
+    #[serde(default)]
+    pub item_class: u32,
+    /// Path of WebM video, for animated items
+    pub movie_webm: Option<String>,
+    /// Path of MP4 video, for animated items
+    pub movie_mp4: Option<String>,
+}
+
+impl ProfileItem {
+    /// Returns url of small image, if the item has one.
+    pub fn image_small_url(&self) -> Option<String> {
+        community_image_url(self.image_small.as_deref())
+    }
+
+    /// Returns url of large image, if the item has one.
+    pub fn image_large_url(&self) -> Option<String> {
+        community_image_url(self.image_large.as_deref())
+    }
+
+    /// Returns url of WebM video, if the item has one.
+    pub fn movie_webm_url(&self) -> Option<String> {
+        community_image_url(self.movie_webm.as_deref())
+    }
+
+    /// Returns url of MP4 video, if the item has one.
+    pub fn movie_mp4_url(&self) -> Option<String> {
+        community_image_url(self.movie_mp4.as_deref())
+    }
+}
+
+fn community_image_url(path: Option<&str>) -> Option<String> {
+    path.filter(|path| !path.is_empty())
+        .map(|path| format!("{}/{}", COMMUNITY_IMAGE_URL, path.trim_start_matches('/')))
+}
+
+/// Slots without equipped item are returned as empty objects.
+pub(crate) fn equipped<'de, D>(d: D) -> Result<Option<ProfileItem>, D::Error>
+    where
+        D: Deserializer<'de>,
+{
+    /// Fields of [ProfileItem], which are missing in empty slots.
+    #[derive(Deserialize)]
+    struct Slot {
+        communityitemid: Option<String>,
+        image_small: Option<String>,
+        image_large: Option<String>,
+        #[serde(default)]
+        name: String,
+        #[serde(default)]
+        item_title: String,
+        #[serde(default)]
+        item_description: String,
+        appid: Option<AppId>,
+        #[serde(default)]
+        item_type: u32,
+        #[serde(default)]
+        item_class: u32,
+        movie_webm: Option<String>,
+        movie_mp4: Option<String>,
+    }
+
+    let slot: Option<Slot> = Deserialize::deserialize(d)?;
+    let slot = match slot {
+        Some(slot) => slot,
+        None => return Ok(None),
+    };
+    let communityitemid = match slot.communityitemid {
+        Some(communityitemid) => communityitemid,
+        None => return Ok(None),
+    };
+
+    Ok(Some(ProfileItem {
+        communityitemid,
+        image_small: slot.image_small,
+        image_large: slot.image_large,
+        name: slot.name,
+        item_title: slot.item_title,
+        item_description: slot.item_description,
+        appid: slot.appid.ok_or_else(|| D::Error::missing_field("appid"))?,
+        item_type: slot.item_type,
+        item_class: slot.item_class,
+        movie_webm: slot.movie_webm,
+        movie_mp4: slot.movie_mp4,
+    }))
+}
@@ -0,0 +1,60 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{AppId, SteamId};
+
+/// Entity representing app ownership of the player
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct AppOwnership {
+    /// ID of the app, missing when checking single app
+    pub appid: Option<AppId>,
+    /// Player owns the app
+    pub ownsapp: bool,
+    /// License is permanent, not e.g. free weekend
+    #[serde(default)]
+    pub permanent: bool,
+    /// Time the license was granted
+    pub timestamp: Option<String>,
+    /// ID of the owner, differs from the player for family shared apps
+    pub ownersteamid: Option<String>,
+    /// License comes from site license, e.g. internet cafe
+    #[serde(default)]
+    pub sitelicense: bool,
+    /// License is timed trial
+    #[serde(default)]
+    pub timedtrial: bool,
+}
+
+/// Result of session ticket validation
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct UserTicket {
+    /// Result of the validation, `OK` on success
+    pub result: String,
+    /// ID of the ticket owner
+    pub steamid: SteamId,
+    /// ID of the app owner, differs from `steamid` for family shared apps
+    pub ownersteamid: SteamId,
+    /// Player is VAC banned
+    pub vacbanned: bool,
+    /// Player is banned by the publisher
+    pub publisherbanned: bool,
+}
+
+/// Beta branch of an app
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct AppBeta {
+    /// Branch name
+    #[serde(default)]
+    pub name: String,
+    /// ID of the build set live on the branch
+    #[serde(alias = "BuildID")]
+    pub build_id: u64,
+    /// Branch description
+    #[serde(alias = "Description")]
+    pub description: String,
+    /// Branch is protected by password
+    #[serde(alias = "ReqPassword")]
+    pub requires_password: bool,
+    /// Branch needs local content server
+    #[serde(alias = "ReqLocalCS")]
+    pub requires_local_content_server: bool,
+}
@@ -0,0 +1,284 @@
+use std::fmt::{Display, Formatter};
+
+use serde::{Deserialize, Deserializer, Serialize};
+
+use crate::{AppId, SteamId};
+
+/// Master server filter for querying game servers.
+///
+/// # Examples
+/// ```rust
+/// use steam_api_client::AppId;
+/// use steam_api_client::entity::ServerFilter;
+///
+/// let filter = ServerFilter::new().appid(AppId(730)).dedicated().not_empty();
+/// assert_eq!(filter.to_string(), r"\appid\730\dedicated\1\empty\1");
+/// ```
+///
+/// Backslashes separate conditions, so they are removed from values passed to the setters.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ServerFilter {
+    conditions: Vec<(&'static str, String)>,
+}
+
+impl ServerFilter {
+    /// Creates empty filter, matching all servers.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn with(self, name: &'static str, value: impl Display) -> Self {
+        self.with_raw(name, value.to_string().replace('\\', ""))
+    }
+
+    fn with_raw(mut self, name: &'static str, value: String) -> Self {
+        self.conditions.push((name, value));
+        self
+    }
+
+    /// Servers running given app.
+    pub fn appid(self, appid: AppId) -> Self {
+        self.with("appid", appid)
+    }
+
+    /// Servers not running given app.
+    pub fn not_appid(self, appid: AppId) -> Self {
+        self.with("napp", appid)
+    }
+
+    /// Servers running given mod directory.
+    pub fn gamedir(self, gamedir: &str) -> Self {
+        self.with("gamedir", gamedir)
+    }
+
+    /// Servers running given map.
+    pub fn map(self, map: &str) -> Self {
+        self.with("map", map)
+    }
+
+    /// Dedicated servers.
+    pub fn dedicated(self) -> Self {
+        self.with("dedicated", 1)
+    }
+
+    /// Servers using anti-cheat.
+    pub fn secure(self) -> Self {
+        self.with("secure", 1)
+    }
+
+    /// Servers running on Linux.
+    pub fn linux(self) -> Self {
+        self.with("linux", 1)
+    }
+
+    /// Servers with or without password.
+    pub fn password(self, password: bool) -> Self {
+        self.with("password", u8::from(password))
+    }
+
+    /// Servers which are not empty.
+    pub fn not_empty(self) -> Self {
+        self.with("empty", 1)
+    }
+
+    /// Servers which are not full.
+    pub fn not_full(self) -> Self {
+        self.with("full", 1)
+    }
+
+    /// Servers with no players.
+    pub fn no_players(self) -> Self {
+        self.with("noplayers", 1)
+    }
+
+    /// Spectator proxies.
+    pub fn proxy(self) -> Self {
+        self.with("proxy", 1)
+    }
+
+    /// Whitelisted servers.
+    pub fn white(self) -> Self {
+        self.with("white", 1)
+    }
+
+    /// Servers with all given tags.
+    pub fn tags(self, tags: &[&str]) -> Self {
+        self.with("gametype", tags.join(","))
+    }
+
+    /// Servers with all given hidden tags.
+    pub fn gamedata(self, tags: &[&str]) -> Self {
+        self.with("gamedata", tags.join(","))
+    }
+
+    /// Servers with any of given hidden tags.
+    pub fn gamedata_or(self, tags: &[&str]) -> Self {
+        self.with("gamedataor", tags.join(","))
+    }
+
+    /// Servers with matching name, `*` can be used as wildcard.
+    pub fn name_match(self, name: &str) -> Self {
+        self.with("name_match", name)
+    }
+
+    /// Servers with matching version, `*` can be used as wildcard.
+    pub fn version_match(self, version: &str) -> Self {
+        self.with("version_match", version)
+    }
+
+    /// Only one server per IP address.
+    pub fn collapse_addr_hash(self) -> Self {
+        self.with("collapse_addr_hash", 1)
+    }
+
+    /// Servers on given IP address, with optional port.
+    pub fn gameaddr(self, addr: &str) -> Self {
+        self.with("gameaddr", addr)
+    }
+
+    /// Servers matching none of the conditions in given filter.
+    ///
+    /// `nor` or `nand` inside given filter counts as a single condition.
+    pub fn nor(self, filter: ServerFilter) -> Self {
+        self.with_raw("nor", filter.nested())
+    }
+
+    /// Servers not matching all of the conditions in given filter.
+    ///
+    /// `nor` or `nand` inside given filter counts as a single condition.
+    pub fn nand(self, filter: ServerFilter) -> Self {
+        self.with_raw("nand", filter.nested())
+    }
+
+    fn nested(&self) -> String {
+        format!("{}{}", self.conditions.len(), self)
+    }
+}
+
+impl Display for ServerFilter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (name, value) in &self.conditions {
+            write!(f, "\\{}\\{}", name, value)?;
+        }
+        Ok(())
+    }
+}
+
+/// Entity representing game server
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct GameServer {
+    /// Server address with query port
+    pub addr: String,
+    /// Game port
+    pub gameport: u16,
+    /// ID of the server
+    pub steamid: SteamId,
+    /// Server name
+    pub name: String,
+    /// ID of the app
+    pub appid: AppId,
+    /// Mod directory
+    pub gamedir: String,
+    /// Server version
+    pub version: String,
+    /// Product name
+    pub product: String,
+    /// Server region
+    pub region: i32,
+    /// Number of players
+    pub players: u32,
+    /// Maximum number of players
+    pub max_players: u32,
+    /// Number of bots
+    pub bots: u32,
+    /// Current map
+    pub map: String,
+    /// Server uses anti-cheat
+    pub secure: bool,
+    /// Server is dedicated
+    pub dedicated: bool,
+    /// Server operating system, `l` for Linux, `w` for Windows
+    pub os: String,
+    /// Server tags
+    #[serde(alias = "gametype", default, deserialize_with = "comma_list")]
+    pub tags: Vec<String>,
+}
+
+/// Entity representing game server found on an address
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct ServerAtAddress {
+    /// Server address with query port
+    pub addr: String,
+    /// Game port
+    pub gameport: u16,
+    /// Spectator port
+    pub specport: Option<u16>,
+    /// ID of the server
+    pub steamid: SteamId,
+    /// ID of the app
+    pub appid: AppId,
+    /// Mod directory
+    pub gamedir: String,
+    /// Server region
+    pub region: i32,
+    /// Server uses anti-cheat
+    pub secure: bool,
+    /// Server is on local network
+    pub lan: bool,
+}
+
+fn comma_list<'de, D>(d: D) -> Result<Vec<String>, D::Error>
+    where
+        D: Deserializer<'de>,
+{
+    let list: String = Deserialize::deserialize(d)?;
+    Ok(list
+        .split(',')
+        .filter(|tag| !tag.is_empty())
+        .map(|tag| tag.to_owned())
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ServerFilter;
+    use crate::AppId;
+
+    #[test]
+    fn strips_backslashes_from_values() {
+        let filter = ServerFilter::new().map(r"de_dust2\secure\0").name_match(r"\\");
+
+        assert_eq!(filter.to_string(), r"\map\de_dust2secure0\name_match\");
+    }
+
+    #[test]
+    fn counts_nested_conditions() {
+        let filter = ServerFilter::new()
+            .appid(AppId(730))
+            .nor(ServerFilter::new().map("de_dust2").map("de_nuke").password(true));
+
+        assert_eq!(filter.to_string(), r"\appid\730\nor\3\map\de_dust2\map\de_nuke\password\1");
+    }
+
+    #[test]
+    fn counts_operator_inside_nested_filter_once() {
+        let filter = ServerFilter::new().nand(
+            ServerFilter::new()
+                .dedicated()
+                .nor(ServerFilter::new().linux().secure())
+                .not_full(),
+        );
+
+        assert_eq!(
+            filter.to_string(),
+            r"\nand\3\dedicated\1\nor\2\linux\1\secure\1\full\1"
+        );
+    }
+
+    #[test]
+    fn strips_backslashes_inside_nested_filter() {
+        let filter = ServerFilter::new().nor(ServerFilter::new().gamedir(r"csgo\appid\10"));
+
+        assert_eq!(filter.to_string(), r"\nor\1\gamedir\csgoappid10");
+    }
+}
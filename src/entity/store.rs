@@ -0,0 +1,76 @@
+use serde::{Deserialize, Serialize};
+
+use crate::AppId;
+
+/// Store page details of an app
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct AppDetails {
+    /// ID of the app
+    #[serde(alias = "steam_appid")]
+    pub appid: AppId,
+    /// Type of the app, e.g. `game` or `dlc`
+    #[serde(rename = "type")]
+    pub app_type: String,
+    /// App name
+    pub name: String,
+    /// App is free to play
+    #[serde(default)]
+    pub is_free: bool,
+    /// Localized short description
+    #[serde(default)]
+    pub short_description: String,
+    /// Url of header image
+    pub header_image: Option<String>,
+    /// Price in the store region, missing for free and unreleased apps
+    pub price_overview: Option<PriceOverview>,
+    /// Release date
+    pub release_date: Option<ReleaseDate>,
+    /// Supported platforms
+    pub platforms: Option<Platforms>,
+    /// Developer names
+    #[serde(default)]
+    pub developers: Vec<String>,
+    /// Publisher names
+    #[serde(default)]
+    pub publishers: Vec<String>,
+}
+
+/// Price of an app
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct PriceOverview {
+    /// Currency code
+    pub currency: String,
+    /// Price before discount in the smallest currency unit
+    pub initial: u64,
+    /// Price after discount in the smallest currency unit
+    #[serde(rename = "final")]
+    pub final_price: u64,
+    /// Discount in percent
+    pub discount_percent: u32,
+    /// Formatted price before discount, empty without discount
+    #[serde(default)]
+    pub initial_formatted: String,
+    /// Formatted price after discount
+    #[serde(default)]
+    pub final_formatted: String,
+}
+
+/// Release date of an app
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct ReleaseDate {
+    /// App is not released yet
+    pub coming_soon: bool,
+    /// Localized release date
+    pub date: String,
+}
+
+/// Platforms supported by an app
+#[derive(Copy, Clone, Serialize, Deserialize, Debug)]
+pub struct Platforms {
+    /// Windows support
+    pub windows: bool,
+    /// macOS support
+    pub mac: bool,
+    /// Linux support
+    pub linux: bool,
+}
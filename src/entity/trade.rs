@@ -0,0 +1,401 @@
+use serde::{Deserialize, Serialize};
+
+use crate::AppId;
+
+use super::{any_to_bool, AssetClassInfo};
+
+/// Parameters for listing trade offers.
+///
+/// Use [TradeOffers::next_cursor] as `cursor` to get the next page.
+#[derive(Clone, Debug, Default)]
+pub struct TradeOffersQuery {
+    /// Include offers sent by the user
+    pub get_sent_offers: bool,
+    /// Include offers received by the user
+    pub get_received_offers: bool,
+    /// Include descriptions of traded items
+    pub get_descriptions: bool,
+    /// Optional language of descriptions
+    pub language: Option<String>,
+    /// Include only active offers, and offers changed since `time_historical_cutoff`
+    pub active_only: bool,
+    /// Include only inactive offers
+    pub historical_only: bool,
+    /// Timestamp used with `active_only`
+    pub time_historical_cutoff: Option<u64>,
+    /// Cursor of the page to get
+    pub cursor: Option<u32>,
+}
+
+/// Parameters for listing trade history.
+///
+/// Use `time_init` and `tradeid` of the last trade as `start_after_time` and `start_after_tradeid` to get the next page.
+#[derive(Clone, Debug)]
+pub struct TradeHistoryQuery {
+    /// Maximum number of trades to return
+    pub max_trades: u32,
+    /// Return trades started after this timestamp
+    pub start_after_time: Option<u64>,
+    /// Return trades after this trade id
+    pub start_after_tradeid: Option<String>,
+    /// Page backwards
+    pub navigating_back: bool,
+    /// Include descriptions of traded items
+    pub get_descriptions: bool,
+    /// Optional language of descriptions
+    pub language: Option<String>,
+    /// Include failed trades
+    pub include_failed: bool,
+    /// Include total number of trades
+    pub include_total: bool,
+}
+
+impl Default for TradeHistoryQuery {
+    fn default() -> Self {
+        Self {
+            max_trades: 100,
+            start_after_time: None,
+            start_after_tradeid: None,
+            navigating_back: false,
+            get_descriptions: false,
+            language: None,
+            include_failed: false,
+            include_total: false,
+        }
+    }
+}
+
+/// Page of trade offers
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct TradeOffers {
+    /// Offers sent by the user
+    #[serde(default)]
+    pub trade_offers_sent: Vec<TradeOffer>,
+    /// Offers received by the user
+    #[serde(default)]
+    pub trade_offers_received: Vec<TradeOffer>,
+    /// Descriptions of traded items, if requested
+    #[serde(default)]
+    pub descriptions: Vec<AssetClassInfo>,
+    /// Cursor of the next page, `None` on the last page
+    #[serde(default, deserialize_with = "zero_to_none")]
+    pub next_cursor: Option<u32>,
+}
+
+/// Single trade offer with item descriptions
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct TradeOfferDetail {
+    /// The offer
+    pub offer: TradeOffer,
+    /// Descriptions of traded items, if requested
+    #[serde(default)]
+    pub descriptions: Vec<AssetClassInfo>,
+}
+
+/// Entity representing trade offer
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct TradeOffer {
+    /// ID of the offer
+    pub tradeofferid: String,
+    /// Account ID of the other party
+    pub accountid_other: u32,
+    /// Optional message from the sender
+    pub message: Option<String>,
+    /// Expiration timestamp
+    pub expiration_time: u64,
+    /// State of the offer
+    pub trade_offer_state: TradeOfferState,
+    /// Items the user gives
+    #[serde(default)]
+    pub items_to_give: Vec<TradeAsset>,
+    /// Items the user receives
+    #[serde(default)]
+    pub items_to_receive: Vec<TradeAsset>,
+    /// Offer was sent by the user
+    pub is_our_offer: bool,
+    /// Creation timestamp
+    pub time_created: u64,
+    /// Last update timestamp
+    pub time_updated: u64,
+    /// ID of the trade, once accepted
+    pub tradeid: Option<String>,
+    /// Offer was created from real-time trade
+    #[serde(default)]
+    pub from_real_time_trade: bool,
+    /// Timestamp of the end of trade hold, 0 for no hold
+    #[serde(default)]
+    pub escrow_end_date: u64,
+    /// Method used to confirm the offer
+    #[serde(default)]
+    pub confirmation_method: u32,
+}
+
+/// Item included in trade offer
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct TradeAsset {
+    /// ID of the app
+    pub appid: AppId,
+    /// ID of the inventory context
+    pub contextid: String,
+    /// ID of the asset
+    pub assetid: String,
+    /// ID of the asset class
+    pub classid: String,
+    /// ID of the asset class instance
+    pub instanceid: String,
+    /// Amount of the asset
+    pub amount: String,
+    /// Item is no longer in the inventory
+    #[serde(default, deserialize_with = "any_to_bool")]
+    pub missing: bool,
+}
+
+/// State of trade offer
+#[derive(Copy, Clone, Serialize, Deserialize, Debug, PartialEq, Eq, Hash)]
+#[serde(from = "u32", into = "u32")]
+pub enum TradeOfferState {
+    /// Invalid offer
+    Invalid,
+    /// Offer was sent and can be acted on
+    Active,
+    /// Offer was accepted and items were exchanged
+    Accepted,
+    /// Recipient made a counter offer
+    Countered,
+    /// Offer was not accepted in time
+    Expired,
+    /// Sender canceled the offer
+    Canceled,
+    /// Recipient declined the offer
+    Declined,
+    /// Some of the items are no longer available
+    InvalidItems,
+    /// Offer waits for confirmation by the sender
+    CreatedNeedsConfirmation,
+    /// Offer was canceled by second factor confirmation
+    CanceledBySecondFactor,
+    /// Offer is on trade hold
+    InEscrow,
+    /// State not known to this library
+    Unknown(u32),
+}
+
+impl From<u32> for TradeOfferState {
+    fn from(value: u32) -> Self {
+        match value {
+            1 => Self::Invalid,
+            2 => Self::Active,
+            3 => Self::Accepted,
+            4 => Self::Countered,
+            5 => Self::Expired,
+            6 => Self::Canceled,
+            7 => Self::Declined,
+            8 => Self::InvalidItems,
+            9 => Self::CreatedNeedsConfirmation,
+            10 => Self::CanceledBySecondFactor,
+            11 => Self::InEscrow,
+            other => Self::Unknown(other),
+        }
+    }
+}
+
+impl From<TradeOfferState> for u32 {
+    fn from(value: TradeOfferState) -> Self {
+        match value {
+            TradeOfferState::Invalid => 1,
+            TradeOfferState::Active => 2,
+            TradeOfferState::Accepted => 3,
+            TradeOfferState::Countered => 4,
+            TradeOfferState::Expired => 5,
+            TradeOfferState::Canceled => 6,
+            TradeOfferState::Declined => 7,
+            TradeOfferState::InvalidItems => 8,
+            TradeOfferState::CreatedNeedsConfirmation => 9,
+            TradeOfferState::CanceledBySecondFactor => 10,
+            TradeOfferState::InEscrow => 11,
+            TradeOfferState::Unknown(other) => other,
+        }
+    }
+}
+
+/// Counts of trade offers
+#[derive(Clone, Serialize, Deserialize, Debug, Default)]
+#[serde(default)]
+pub struct TradeOffersSummary {
+    /// Received offers waiting for action
+    pub pending_received_count: u32,
+    /// Received offers since last visit
+    pub new_received_count: u32,
+    /// Received offers updated since last visit
+    pub updated_received_count: u32,
+    /// Received offers no longer active
+    pub historical_received_count: u32,
+    /// Sent offers waiting for action
+    pub pending_sent_count: u32,
+    /// Sent offers accepted since last visit
+    pub newly_accepted_sent_count: u32,
+    /// Sent offers updated since last visit
+    pub updated_sent_count: u32,
+    /// Sent offers no longer active
+    pub historical_sent_count: u32,
+    /// Received offers on trade hold
+    pub escrow_received_count: u32,
+    /// Sent offers on trade hold
+    pub escrow_sent_count: u32,
+}
+
+/// Page of trade history
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct TradeHistory {
+    /// More trades are available
+    #[serde(default)]
+    pub more: bool,
+    /// Total number of trades, if requested
+    pub total_trades: Option<u32>,
+    /// Completed trades
+    #[serde(default)]
+    pub trades: Vec<Trade>,
+    /// Descriptions of traded items, if requested
+    #[serde(default)]
+    pub descriptions: Vec<AssetClassInfo>,
+}
+
+/// Entity representing completed trade
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct Trade {
+    /// ID of the trade
+    pub tradeid: String,
+    /// ID of the other party
+    pub steamid_other: String,
+    /// Timestamp of the trade start
+    pub time_init: u64,
+    /// Timestamp of the end of trade hold
+    pub time_escrow_end: Option<u64>,
+    /// Status of the trade
+    pub status: TradeStatus,
+    /// Items given by the user
+    #[serde(default)]
+    pub assets_given: Vec<TradedAsset>,
+    /// Items received by the user
+    #[serde(default)]
+    pub assets_received: Vec<TradedAsset>,
+}
+
+/// Item exchanged in trade
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct TradedAsset {
+    /// ID of the app
+    pub appid: AppId,
+    /// ID of the inventory context
+    pub contextid: String,
+    /// ID of the asset before the trade
+    pub assetid: String,
+    /// Amount of the asset
+    pub amount: String,
+    /// ID of the asset class
+    pub classid: String,
+    /// ID of the asset class instance
+    pub instanceid: String,
+    /// ID of the asset after the trade
+    pub new_assetid: Option<String>,
+    /// ID of the inventory context after the trade
+    pub new_contextid: Option<String>,
+}
+
+/// Status of trade
+#[derive(Copy, Clone, Serialize, Deserialize, Debug, PartialEq, Eq, Hash)]
+#[serde(from = "u32", into = "u32")]
+pub enum TradeStatus {
+    /// Trade was started
+    Init,
+    /// Trade is being committed
+    PreCommitted,
+    /// Trade was committed
+    Committed,
+    /// Trade is complete
+    Complete,
+    /// Trade failed
+    Failed,
+    /// Trade was partially rolled back by support
+    PartialSupportRollback,
+    /// Trade was fully rolled back by support
+    FullSupportRollback,
+    /// Selected items were rolled back by support
+    SupportRollbackSelective,
+    /// Rollback failed
+    RollbackFailed,
+    /// Rollback was abandoned
+    RollbackAbandoned,
+    /// Trade is on trade hold
+    InEscrow,
+    /// Trade hold was rolled back
+    EscrowRollback,
+    /// Status not known to this library
+    Unknown(u32),
+}
+
+impl From<u32> for TradeStatus {
+    fn from(value: u32) -> Self {
+        match value {
+            0 => Self::Init,
+            1 => Self::PreCommitted,
+            2 => Self::Committed,
+            3 => Self::Complete,
+            4 => Self::Failed,
+            5 => Self::PartialSupportRollback,
+            6 => Self::FullSupportRollback,
+            7 => Self::SupportRollbackSelective,
+            8 => Self::RollbackFailed,
+            9 => Self::RollbackAbandoned,
+            10 => Self::InEscrow,
+            11 => Self::EscrowRollback,
+            other => Self::Unknown(other),
+        }
+    }
+}
+
+impl From<TradeStatus> for u32 {
+    fn from(value: TradeStatus) -> Self {
+        match value {
+            TradeStatus::Init => 0,
+            TradeStatus::PreCommitted => 1,
+            TradeStatus::Committed => 2,
+            TradeStatus::Complete => 3,
+            TradeStatus::Failed => 4,
+            TradeStatus::PartialSupportRollback => 5,
+            TradeStatus::FullSupportRollback => 6,
+            TradeStatus::SupportRollbackSelective => 7,
+            TradeStatus::RollbackFailed => 8,
+            TradeStatus::RollbackAbandoned => 9,
+            TradeStatus::InEscrow => 10,
+            TradeStatus::EscrowRollback => 11,
+            TradeStatus::Unknown(other) => other,
+        }
+    }
+}
+
+/// Trade hold durations for trade with other user
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct TradeHoldDurations {
+    /// Hold caused by the user
+    pub my_escrow: TradeHoldDuration,
+    /// Hold caused by the other party
+    pub their_escrow: TradeHoldDuration,
+    /// Resulting hold of the trade
+    pub both_escrow: TradeHoldDuration,
+}
+
+/// Single trade hold duration
+#[derive(Copy, Clone, Serialize, Deserialize, Debug)]
+pub struct TradeHoldDuration {
+    /// Length of the hold in seconds
+    #[serde(default)]
+    pub escrow_end_duration_seconds: u64,
+}
+
+fn zero_to_none<'de, D>(d: D) -> Result<Option<u32>, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+{
+    Deserialize::deserialize(d).map(|e: Option<u32>| e.filter(|&c| c != 0))
+}
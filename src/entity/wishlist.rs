@@ -0,0 +1,26 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{error::SasError, AppId};
+
+use super::AppDetails;
+
+/// Entity representing wishlisted app
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct WishlistItem {
+    /// ID of the app
+    pub appid: AppId,
+    /// Priority set by the player, 0 for unranked items
+    #[serde(default)]
+    pub priority: u32,
+    /// Timestamp of adding the app
+    pub date_added: u64,
+}
+
+/// Wishlisted app with store details
+#[derive(Debug)]
+pub struct WishlistEntry {
+    /// Wishlisted app
+    pub item: WishlistItem,
+    /// Store details, `None` for apps no longer in the store, error when the store request failed
+    pub details: Result<Option<AppDetails>, SasError>,
+}
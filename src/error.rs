@@ -1,16 +1,18 @@
-use std::borrow::Cow;
+use std::{borrow::Cow, path::PathBuf, time::Duration};
 
-use reqwest::StatusCode;
+use reqwest::{header::HeaderMap, StatusCode};
 use thiserror::Error;
 
+use crate::auth::redact_url;
+
 /// Error type for this library.
 #[derive(Debug, Error)]
 pub enum SasError {
-    /// Network error
+    /// Network error, credentials are redacted from its url
     #[error("Could not connect to Steam API")]
     ReqwestError {
         /// Source of the network error
-        #[from]
+        #[source]
         source: reqwest::Error,
     },
     /// Error returned from Steam API
@@ -19,19 +21,226 @@ pub enum SasError {
         /// Error message
         msg: Cow<'static, str>,
         /// Error code
-        status: StatusCode
+        status: StatusCode,
+        /// Response details, when enabled with [SteamClient::set_diagnostics](crate::SteamClient::set_diagnostics)
+        diagnostics: Option<Box<ResponseDiagnostics>>,
+    },
+    /// Client is missing credentials needed by the endpoint
+    #[error("Endpoint {endpoint} requires {required}")]
+    MissingCredential {
+        /// Endpoint resource
+        endpoint: String,
+        /// Description of the missing credential
+        required: &'static str,
+    },
+    /// Session ticket was rejected by Steam, e.g. invalid, expired or for other app
+    #[error("Invalid ticket ({code}): {msg}")]
+    InvalidTicket {
+        /// Error code returned by Steam
+        code: i32,
+        /// Error description returned by Steam
+        msg: String,
     },
-    /// Format/structure error
-    #[error("Deserialization Error: {source}")]
-    SerdeError {
+    /// OpenID login could not be verified
+    #[error("OpenID verification failed: {0}")]
+    OpenIdError(Cow<'static, str>),
+    /// File or stream could not be read or written, e.g. image cache
+    #[error("I/O Error: {source}")]
+    IoError {
         /// Source of the error
         #[from]
-        source: serde_json::error::Error,
-    },
-    /// General HTTP error
-    #[error("HTTP Error: {0}")]
-    ApiError(String),
-    /// General Error
-    #[error("Internal Error: {0}")]
-    InternalError(Cow<'static, str>),
+        source: std::io::Error,
+    },
+    /// Method needs image cache, but none is set
+    #[error("Image cache is not set")]
+    NoImageCache,
+    /// Image is larger than allowed size
+    #[error("Image exceeds maximum size of {max_size} bytes")]
+    ImageTooLarge {
+        /// Maximum allowed size in bytes
+        max_size: u64,
+    },
+    /// Response was expected to be an image, e.g. error page served with success status
+    #[error("Expected image, received {}", content_type.as_deref().unwrap_or("unknown content"))]
+    UnexpectedContent {
+        /// Content type reported by the server, `None` when missing
+        content_type: Option<String>,
+    },
+    /// Too many requests were sent, Steam allows about 100 000 calls per day
+    #[error("Rate limited by Steam API")]
+    RateLimited {
+        /// Time to wait before retrying, when sent by Steam
+        retry_after: Option<Duration>,
+        /// Response details, when enabled with [SteamClient::set_diagnostics](crate::SteamClient::set_diagnostics)
+        diagnostics: Option<Box<ResponseDiagnostics>>,
+    },
+    /// Credentials were rejected, e.g. invalid api key
+    #[error("Unauthorized, credentials were rejected")]
+    Unauthorized {
+        /// Response details, when enabled with [SteamClient::set_diagnostics](crate::SteamClient::set_diagnostics)
+        diagnostics: Option<Box<ResponseDiagnostics>>,
+    },
+    /// Requested data is not visible because of privacy settings of the profile
+    #[error("Profile is private")]
+    PrivateProfile {
+        /// Response details, when enabled with [SteamClient::set_diagnostics](crate::SteamClient::set_diagnostics)
+        diagnostics: Option<Box<ResponseDiagnostics>>,
+    },
+    /// Requested resource does not exist
+    #[error("Not found")]
+    NotFound {
+        /// Response details, when enabled with [SteamClient::set_diagnostics](crate::SteamClient::set_diagnostics)
+        diagnostics: Option<Box<ResponseDiagnostics>>,
+    },
+    /// Steam failed to handle the request
+    #[error("Steam server error: {status}")]
+    ServerError {
+        /// Status code of the response
+        status: StatusCode,
+        /// Response details, when enabled with [SteamClient::set_diagnostics](crate::SteamClient::set_diagnostics)
+        diagnostics: Option<Box<ResponseDiagnostics>>,
+    },
+    /// Response has unexpected format
+    #[error("Could not decode response of {endpoint}: {source}")]
+    Decode {
+        /// Endpoint path, without query
+        endpoint: String,
+        /// Source of the error
+        source: Box<dyn std::error::Error + Send + Sync>,
+        /// Start of the response body
+        body_snippet: String,
+        /// Response details, when enabled with [SteamClient::set_diagnostics](crate::SteamClient::set_diagnostics)
+        diagnostics: Option<Box<ResponseDiagnostics>>,
+    },
+    /// Host url set with [SteamClient::set_host](crate::SteamClient::set_host) is not valid
+    #[error("Invalid url: {url}")]
+    InvalidUrl {
+        /// The invalid url
+        url: String,
+    },
+}
+
+/// Details of failed or undecodable response.
+#[derive(Debug, Clone)]
+pub struct ResponseDiagnostics {
+    /// Request url, with credentials redacted
+    pub url: String,
+    /// Status code of the response
+    pub status: StatusCode,
+    /// Response headers
+    pub headers: HeaderMap,
+    /// Response body, truncated
+    pub body: String,
+    /// File with the full response, when dump directory is set
+    pub dump_path: Option<PathBuf>,
+}
+
+impl From<reqwest::Error> for SasError {
+    fn from(mut source: reqwest::Error) -> Self {
+        // reqwest includes the url in its messages, which would leak credentials from query
+        if let Some(url) = source.url_mut() {
+            redact_url(url);
+        }
+
+        Self::ReqwestError { source }
+    }
+}
+
+/// Maximum length of body included in [SasError::Decode].
+const BODY_SNIPPET_LEN: usize = 256;
+
+impl SasError {
+    /// Returns `true` for errors which may not happen when the request is sent again later.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use reqwest::StatusCode;
+    /// use steam_api_client::SasError;
+    ///
+    /// assert!(SasError::ServerError { status: StatusCode::BAD_GATEWAY, diagnostics: None }.is_retriable());
+    /// assert!(!SasError::Unauthorized { diagnostics: None }.is_retriable());
+    /// ```
+    pub fn is_retriable(&self) -> bool {
+        match self {
+            Self::RateLimited { .. } | Self::ServerError { .. } => true,
+            Self::ReqwestError { source } => source.is_timeout() || source.is_connect(),
+            _ => false,
+        }
+    }
+
+    /// Returns details of the response which caused the error, when diagnostics are enabled.
+    pub fn diagnostics(&self) -> Option<&ResponseDiagnostics> {
+        match self {
+            Self::SteamApiError { diagnostics, .. }
+            | Self::RateLimited { diagnostics, .. }
+            | Self::Unauthorized { diagnostics }
+            | Self::PrivateProfile { diagnostics }
+            | Self::NotFound { diagnostics }
+            | Self::ServerError { diagnostics, .. }
+            | Self::Decode { diagnostics, .. } => diagnostics.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Returns slot for response details, for errors caused by a response.
+    pub(crate) fn diagnostics_mut(&mut self) -> Option<&mut Option<Box<ResponseDiagnostics>>> {
+        match self {
+            Self::SteamApiError { diagnostics, .. }
+            | Self::RateLimited { diagnostics, .. }
+            | Self::Unauthorized { diagnostics }
+            | Self::PrivateProfile { diagnostics }
+            | Self::NotFound { diagnostics }
+            | Self::ServerError { diagnostics, .. }
+            | Self::Decode { diagnostics, .. } => Some(diagnostics),
+            _ => None,
+        }
+    }
+
+    /// Returns error for unsuccessful status of response.
+    ///
+    /// Steam answers with 403 both for rejected credentials and for profiles hidden by privacy settings,
+    /// only the body tells them apart.
+    pub(crate) fn from_status(status: StatusCode, retry_after: Option<Duration>, body: &[u8]) -> Self {
+        match status {
+            StatusCode::TOO_MANY_REQUESTS => Self::RateLimited {
+                retry_after,
+                diagnostics: None,
+            },
+            StatusCode::FORBIDDEN if mentions_privacy(body) => Self::PrivateProfile { diagnostics: None },
+            StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => Self::Unauthorized { diagnostics: None },
+            StatusCode::NOT_FOUND => Self::NotFound { diagnostics: None },
+            status if status.is_server_error() => Self::ServerError {
+                status,
+                diagnostics: None,
+            },
+            status => Self::SteamApiError {
+                msg: "Unexpected response status".into(),
+                status,
+                diagnostics: None,
+            },
+        }
+    }
+
+    /// Returns decode error with start of given body.
+    pub(crate) fn decode(
+        endpoint: &str,
+        source: impl Into<Box<dyn std::error::Error + Send + Sync>>,
+        body: &[u8],
+    ) -> Self {
+        let body = &body[..body.len().min(BODY_SNIPPET_LEN)];
+
+        Self::Decode {
+            endpoint: endpoint.to_owned(),
+            source: source.into(),
+            body_snippet: String::from_utf8_lossy(body).into_owned(),
+            diagnostics: None,
+        }
+    }
+}
+
+/// Returns `true` for bodies explaining that the profile is private.
+fn mentions_privacy(body: &[u8]) -> bool {
+    let body = String::from_utf8_lossy(body).to_lowercase();
+
+    body.contains("private") || body.contains("not public")
 }
@@ -4,15 +4,26 @@
 //! Library for communicating with Steam Web APIs.
 //!
 
+mod auth;
 /// Entity specifications.
 pub mod entity;
 mod error;
 mod steam_client;
 
-use std::fmt::{Display, Formatter};
-use serde::{Deserialize, Serialize};
-pub use steam_client::SteamClient;
-pub use error::SasError;
+/// Response envelopes, public only for benchmarks and not part of the API.
+#[doc(hidden)]
+pub mod __bench {
+    pub use crate::steam_client::response::{Envelope, Games, Schema};
+}
+
+use std::{
+    fmt::{Display, Formatter},
+    str::FromStr,
+};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+pub use auth::Auth;
+pub use steam_client::{Host, RawCall, SteamClient};
+pub use error::{ResponseDiagnostics, SasError};
 
 /// Newtype for app ids
 #[derive(Serialize, Deserialize, Copy, Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
@@ -22,4 +33,53 @@ impl Display for AppId {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         self.0.fmt(f)
     }
-}
\ No newline at end of file
+}
+
+/// Newtype for 64-bit steam ids
+///
+/// Serialized as string, as Steam API does.
+#[derive(Copy, Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct SteamId(pub u64);
+
+impl SteamId {
+    /// Returns 32-bit account id part of the steam id.
+    pub fn account_id(&self) -> u32 {
+        (self.0 & 0xFFFF_FFFF) as u32
+    }
+}
+
+impl Display for SteamId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl FromStr for SteamId {
+    type Err = std::num::ParseIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse().map(SteamId)
+    }
+}
+
+impl Serialize for SteamId {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for SteamId {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum StrOrNum {
+            Str(String),
+            Num(u64),
+        }
+
+        match StrOrNum::deserialize(deserializer)? {
+            StrOrNum::Str(s) => s.parse().map_err(serde::de::Error::custom),
+            StrOrNum::Num(n) => Ok(SteamId(n)),
+        }
+    }
+}
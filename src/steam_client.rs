@@ -1,67 +1,207 @@
-use std::fmt::Debug;
+use std::{fmt::Debug, path::PathBuf, time::Duration};
 
 use bytes::Bytes;
-use reqwest::StatusCode;
-use serde::{Deserialize};
-use serde_json::{self, Value};
+use reqwest::{header::RETRY_AFTER, StatusCode};
+use serde::de::DeserializeOwned;
 
 use tracing::{error, info_span};
 use tracing_futures::Instrument;
 
 use crate::{
     AppId,
+    auth::Auth,
     entity::{
-        Game, Player, RecentGame, AchievementPlayerStatsSchema, PlayerStatsSchema,
-        GameSchema, AchievementPercentageSchema
+        AppImage, Game, Image, Player, RecentGame, AchievementPlayerStatsSchema, PlayerStatsSchema,
+        GameSchema, AchievementPercentageSchema, Visibility
     },
     error::SasError
 };
 
+mod cache;
+mod diagnostics;
+mod discovery;
+mod economy;
 mod endpoint;
+mod family;
+mod group;
+mod host;
+mod image;
+mod leaderboard;
+mod openid;
+mod player;
+mod publisher;
+mod raw;
+// envelopes are internal, but reachable by benchmarks through `__bench`
+#[allow(missing_docs)]
+pub(crate) mod response;
+mod server;
+mod store;
+mod trade;
+mod wishlist;
 use endpoint::SteamEndpoint;
-
-use self::endpoint::SteamImageEndpoint;
+pub use host::Host;
+pub use raw::RawCall;
+use host::Hosts;
+use cache::ImageCache;
+use diagnostics::{attach, Capture, Diagnostics};
+use response::{
+    AchievementPercentages, Envelope, Games, PlayerStats, Players, Rejected, Schema, VanityUrl,
+};
 
 /// Client for interaction with Steam API
 pub struct SteamClient {
-    api_key: String,
+    auth: Auth,
+    publisher_key: Option<String>,
+    hosts: Hosts,
+    image_cache: Option<ImageCache>,
+    diagnostics: Option<Diagnostics>,
     client: reqwest::Client,
 }
 
 impl SteamClient {
     /// Creates new [SteamClient] with api key from `STEAM_API_KEY` environment variable.
+    ///
+    /// Publisher key is read from `STEAM_PUBLISHER_KEY` environment variable, if set.
     pub fn new() -> Self {
-        Self {
-            api_key: std::env::var("STEAM_API_KEY").unwrap_or_else(|_| "".to_owned()),
-            client: reqwest::Client::new(),
-        }
+        let auth = match std::env::var("STEAM_API_KEY") {
+            Ok(key) if !key.is_empty() => Auth::ApiKey(key),
+            _ => Auth::None,
+        };
+
+        let mut client = Self::with_auth(auth);
+        client.publisher_key = std::env::var("STEAM_PUBLISHER_KEY").ok().filter(|key| !key.is_empty());
+        client
     }
 
     /// Creates new [SteamClient] with provided api key.
     pub fn with_api_key(api_key: String) -> Self {
+        Self::with_auth(Auth::ApiKey(api_key))
+    }
+
+    /// Creates new [SteamClient] with provided credentials.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use steam_api_client::{Auth, SteamClient};
+    ///
+    /// let client = SteamClient::with_auth(Auth::Both {
+    ///     api_key: "apiKey".to_owned(),
+    ///     access_token: "accessToken".to_owned(),
+    /// });
+    /// ```
+    pub fn with_auth(auth: Auth) -> Self {
         Self {
-            api_key,
+            auth,
+            publisher_key: None,
+            hosts: Hosts::default(),
+            image_cache: None,
+            diagnostics: None,
             client: reqwest::Client::new(),
         }
     }
 
-    /// Sets provided api key.
+    /// Sets provided api key, keeping access token.
     pub fn set_api_key(&mut self, api_key: String) {
-        self.api_key = api_key;
+        self.auth = std::mem::take(&mut self.auth).with_api_key(api_key);
     }
 
-    /// Returns list of owned games for given player id.
+    /// Sets provided access token, keeping api key.
+    pub fn set_access_token(&mut self, access_token: String) {
+        self.auth = std::mem::take(&mut self.auth).with_access_token(access_token);
+    }
+
+    /// Sets provided credentials.
+    pub fn set_auth(&mut self, auth: Auth) {
+        self.auth = auth;
+    }
+
+    /// Returns credentials used by the client.
+    pub fn auth(&self) -> &Auth {
+        &self.auth
+    }
+
+    /// Sets publisher key, enabling publisher only methods.
+    ///
+    /// Publisher methods are sent to [Host::Partner].
+    pub fn set_publisher_key(&mut self, publisher_key: String) {
+        self.publisher_key = Some(publisher_key);
+    }
+
+    /// Sets base url of given host.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use steam_api_client::{Host, SteamClient};
     ///
-    /// Can include free games, and game info.
+    /// let mut client = SteamClient::new();
+    /// client.set_host(Host::Api, "http://localhost:8080");
+    /// ```
+    pub fn set_host(&mut self, host: Host, base_url: impl Into<String>) {
+        self.hosts.set(host, base_url.into());
+    }
+
+    /// Sets directory for caching downloaded images, limited to `max_size` bytes.
+    ///
+    /// Cached images are used without request while fresh by `Cache-Control: max-age` of Steam CDN, a day when
+    /// it is not sent, and revalidated afterwards. Stale images are returned when Steam CDN cannot be reached.
+    /// Least recently used images are removed when the cache is full.
     ///
     /// # Examples
     /// ```rust
     /// use steam_api_client::SteamClient;
     ///
+    /// let mut client = SteamClient::new();
+    /// client.set_image_cache(std::env::temp_dir().join("steam-images"), 64 * 1024 * 1024);
+    /// ```
+    pub fn set_image_cache(&mut self, dir: impl Into<PathBuf>, max_size: u64) {
+        self.image_cache = Some(ImageCache::new(dir.into(), max_size));
+    }
+
+    /// Enables or disables diagnostics of failed responses and responses which could not be decoded.
+    ///
+    /// When enabled, errors caused by a response, e.g. [SasError::Decode] or [SasError::ServerError], include
+    /// [ResponseDiagnostics](crate::ResponseDiagnostics) with request url, status, headers and body of the response,
+    /// available with [SasError::diagnostics]. Credentials are redacted from the url.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use steam_api_client::{SasError, SteamClient};
+    ///
+    /// # tokio_test::block_on(async {
+    /// let mut client = SteamClient::new();
+    /// client.set_diagnostics(true);
+    /// match client.get_player_info("playerId").await {
+    ///     Err(e) if e.diagnostics().is_some() => {/*Response details*/}
+    ///     _ => {/*Other result*/}
+    /// }
+    /// # });
+    /// ```
+    pub fn set_diagnostics(&mut self, enabled: bool) {
+        self.diagnostics = enabled.then(Diagnostics::default);
+    }
+
+    /// Enables diagnostics, with full failed and undecodable responses written into given directory.
+    ///
+    /// See [SteamClient::set_diagnostics].
+    pub fn set_diagnostics_dir(&mut self, dir: impl Into<PathBuf>) {
+        self.diagnostics = Some(Diagnostics {
+            dump_dir: Some(dir.into()),
+        });
+    }
+
+    /// Returns list of owned games for given player id.
+    ///
+    /// Can include free games, and game info. Games of private profiles are [Visibility::Private].
+    ///
+    /// # Examples
+    /// ```rust
+    /// use steam_api_client::{entity::Visibility, SteamClient};
+    ///
     /// # tokio_test::block_on(async {
     /// let client = SteamClient::new();
     /// match client.get_owned_games("playerId", true, true).await {
-    ///     Ok(games) => {/*List of games*/},
+    ///     Ok(Visibility::Public(games)) => {/*List of games*/},
+    ///     Ok(Visibility::Private) => {/*Profile is private*/},
     ///     Err(e) => {/*Error*/}
     /// }
     /// # });
@@ -72,53 +212,34 @@ impl SteamClient {
         id: &str,
         include_appinfo: bool,
         include_free_games: bool,
-    ) -> Result<Vec<Game>, SasError> {
+    ) -> Result<Visibility<Vec<Game>>, SasError> {
         let url = SteamEndpoint::GetOwnedGames {
             id,
             include_appinfo,
             include_free_games,
         }
-        .url(&self.api_key);
-
-        let res = self
-            .client
-            .get(url)
-            .send()
-            .instrument(info_span!("API request"))
-            .await?;
-
-        let status = res.status();
-        if !status.is_success() {
-            error!(status = status.as_u16());
-            return Err(SasError::ApiError(format!("Recieved {} from Steam API", status)));
-        }
+        .url(self)?;
 
-        let res = res.json::<Value>().instrument(info_span!("reading from JSON")).await?;
+        let res: Games<Game> = self.get_response(url).await?;
 
-        let games: Vec<Game> = match serde_json::from_value(res["response"]["games"].clone()) {
-            Ok(v) => v,
-            Err(e) => {
-                error!(error = ?e);
-                return Err(SasError::InternalError("Invalid data format".into()));
-            }
-        };
-
-        Ok(games)
+        Ok(res.visibility())
     }
 
     /// Returns list of achievements for given
     ///
-    /// Can include localized achievement name and description.
+    /// Can include localized achievement name and description. Achievements of private profiles are
+    /// [Visibility::Private].
     ///
     /// # Examples
     /// ```rust
-    /// use steam_api_client::{AppId, SteamClient};
+    /// use steam_api_client::{entity::Visibility, AppId, SteamClient};
     ///
     /// # tokio_test::block_on(async {
     /// let client = SteamClient::new();
     /// match client.get_achievements_for_game("playerId", AppId(400), Some("en")).await {
-    ///     Ok(Some(games)) => {/*List of achievements*/}
-    ///     Ok(None) => {/*Game has no achievements*/}
+    ///     Ok(Visibility::Public(Some(games))) => {/*List of achievements*/}
+    ///     Ok(Visibility::Public(None)) => {/*Game has no achievements*/}
+    ///     Ok(Visibility::Private) => {/*Profile is private*/}
     ///     Err(e) => {/*Error*/}
     /// }
     /// # });
@@ -129,8 +250,9 @@ impl SteamClient {
         id: &str,
         appid: AppId,
         lang: Option<&str>,
-    ) -> Result<Option<Vec<AchievementPlayerStatsSchema>>, SasError> {
-        let url = SteamEndpoint::GetPlayerAchievements { id, appid, lang }.url(&self.api_key);
+    ) -> Result<Visibility<Option<Vec<AchievementPlayerStatsSchema>>>, SasError> {
+        let url = SteamEndpoint::GetPlayerAchievements { id, appid, lang }.url(self)?;
+        let endpoint = endpoint_path(&url);
 
         let res = self
             .client
@@ -140,40 +262,46 @@ impl SteamClient {
             .await?;
 
         let status = res.status();
-        if status.as_u16() == 403 {
-            // private profile
-            return Err(SasError::SteamApiError {
-                msg: "Cannot get stats for private profile".into(),
-                status: StatusCode::FORBIDDEN,
-            });
-        }
-        if status.is_server_error() {
-            error!(status = status.as_u16());
-            return Err(SasError::ApiError(format!("Recieved {} from Steam API", status)));
-        }
+        let capture = self.capture(&res);
+        // private profiles and apps without stats come with `playerstats.error` message
+        let res = match status {
+            StatusCode::BAD_REQUEST | StatusCode::FORBIDDEN => res,
+            _ => check_status(res, &capture).await?,
+        };
 
-        let res = res.json::<Value>().instrument(info_span!("reading from JSON")).await?;
-
-        match res["playerstats"].as_object() {
-            Some(playerstats) => {
-                let stats: PlayerStatsSchema = match serde_json::from_value(Value::Object(playerstats.clone())) {
-                    Ok(stats) => stats,
-                    Err(e) => {
-                        error!(error = ?e);
-                        return Err(SasError::InternalError("Invalid data format".into()));
-                    }
-                };
-
-                match stats {
-                    PlayerStatsSchema::Success { achievements, .. } => Ok(Some(achievements)),
-                    PlayerStatsSchema::Error { error } => Err(SasError::SteamApiError {
+        let body = res.bytes().instrument(info_span!("reading from JSON")).await?;
+
+        let error = if !status.is_success() {
+            // rejected keys come without the message
+            match serde_json::from_slice(&body) {
+                Ok(PlayerStats {
+                    playerstats: Some(PlayerStatsSchema::Error { error }),
+                }) => match status {
+                    StatusCode::FORBIDDEN => return Ok(Visibility::Private),
+                    _ if error.contains("no stats") => return Ok(Visibility::Public(None)),
+                    _ => SasError::SteamApiError {
                         msg: error.into(),
-                        status: StatusCode::BAD_GATEWAY,
-                    }),
-                }
+                        status,
+                        diagnostics: None,
+                    },
+                },
+                _ => SasError::from_status(status, None, &body),
             }
-            None => Err(SasError::InternalError("Invalid data format".into())),
-        }
+        } else {
+            let res: PlayerStats = from_slice(&endpoint, &capture, &body).await?;
+
+            match res.playerstats {
+                Some(PlayerStatsSchema::Success { achievements, .. }) => return Ok(Visibility::Public(achievements)),
+                Some(PlayerStatsSchema::Error { error }) => SasError::SteamApiError {
+                    msg: error.into(),
+                    status: StatusCode::BAD_GATEWAY,
+                    diagnostics: None,
+                },
+                None => SasError::decode(&endpoint, "missing playerstats", &body),
+            }
+        };
+
+        Err(attach(&capture, error, &body).await)
     }
 
     /// Returns info about the player with given id.
@@ -193,31 +321,11 @@ impl SteamClient {
     /// ```
     #[tracing::instrument(skip(self))]
     pub async fn get_player_info(&self, id: &str) -> Result<Option<Player>, SasError> {
-        let url = SteamEndpoint::GetPlayerSummaries { steam_id: id }.url(&self.api_key);
+        let url = SteamEndpoint::GetPlayerSummaries { steam_id: id }.url(self)?;
 
-        let res = self
-            .client
-            .get(url)
-            .send()
-            .instrument(info_span!("API request"))
-            .await?;
+        let res: Players = self.get_response(url).await?;
 
-        let status = res.status();
-        if !status.is_success() {
-            return Err(SasError::ApiError(format!("Recieved {} from Steam API", status)));
-        }
-
-        let res = res.json::<Value>().instrument(info_span!("reading from JSON")).await?;
-
-        let players: Vec<Player> = match serde_json::from_value(res["response"]["players"].clone()) {
-            Ok(v) => v,
-            Err(e) => {
-                error!(error = ?e);
-                return Err(SasError::InternalError("Invalid data format".into()));
-            }
-        };
-
-        Ok(players.get(0).cloned())
+        Ok(res.players.into_iter().next())
     }
 
     /// Returns achievement rarities for given app.
@@ -240,7 +348,8 @@ impl SteamClient {
         &self,
         appid: AppId,
     ) -> Result<Option<Vec<AchievementPercentageSchema>>, SasError> {
-        let url = SteamEndpoint::GetGlobalAchievementPercentagesForApp { appid }.url(&self.api_key);
+        let url = SteamEndpoint::GetGlobalAchievementPercentagesForApp { appid }.url(self)?;
+        let endpoint = endpoint_path(&url);
 
         let res = self
             .client
@@ -251,27 +360,17 @@ impl SteamClient {
 
         let status = res.status();
         // no game or achievements
-        if status.as_u16() == 403 {
+        if status == StatusCode::FORBIDDEN {
             return Ok(None);
         }
 
-        if !status.is_success() {
-            error!(status = status.as_u16());
-            return Err(SasError::ApiError(format!("Recieved {} from Steam API", status)));
-        }
-
-        let res = res.json::<Value>().instrument(info_span!("reading from JSON")).await?;
+        let capture = self.capture(&res);
+        let res = check_status(res, &capture).await?;
 
-        let achievements: Vec<AchievementPercentageSchema> =
-            match serde_json::from_value(res["achievementpercentages"]["achievements"].clone()) {
-                Ok(v) => v,
-                Err(e) => {
-                    error!(error = ?e);
-                    return Err(SasError::InternalError("Invalid data format".into()));
-                }
-            };
+        let body = res.bytes().instrument(info_span!("reading from JSON")).await?;
+        let res: AchievementPercentages = from_slice(&endpoint, &capture, &body).await?;
 
-        Ok(Some(achievements))
+        Ok(Some(res.achievementpercentages.achievements))
     }
 
     /// Resolves vanity url.
@@ -293,51 +392,19 @@ impl SteamClient {
     /// ```
     #[tracing::instrument(skip(self))]
     pub async fn resolve_vanity_url(&self, vanity: &str) -> Result<Option<String>, SasError> {
-        #[derive(Debug, Deserialize)]
-        #[serde(untagged)]
-        #[allow(dead_code)]
-        enum GetVanityUrlResponse {
-            Ok { steamid: String, success: i32 },
-            NotFound { message: String, success: i32 },
-        }
-
-        let url = SteamEndpoint::ResolveVanityUrl { url: vanity }.url(&self.api_key);
-
-        let res = self.client.get(url).send().instrument(info_span!("API request")).await?;
-
-        let status = res.status();
-        if !status.is_success() {
-            return Err(SasError::ApiError(format!("Recieved {} from Steam API", status)));
-        }
-
-        let res = res.json::<Value>().await?;
+        let url = SteamEndpoint::ResolveVanityUrl { url: vanity }.url(self)?;
 
-        let vanity_res = serde_json::from_value(res["response"].clone())?;
-
-        if let GetVanityUrlResponse::Ok { steamid, .. } = vanity_res {
+        if let VanityUrl::Ok { steamid, .. } = self.get_response(url).await? {
             return Ok(Some(steamid));
         }
 
         // now try access id directly
 
-        let url = SteamEndpoint::GetPlayerSummaries { steam_id: vanity }.url(&self.api_key);
-
-        let res = self.client.get(url).send().instrument(info_span!("API request")).await?;
+        let url = SteamEndpoint::GetPlayerSummaries { steam_id: vanity }.url(self)?;
 
-        let status = res.status();
-        if !status.is_success() {
-            return Err(SasError::ApiError(format!("Recieved {} from Steam API", status)));
-        }
+        let res: Players = self.get_response(url).await?;
 
-        let res = res.json::<Value>().await?;
-
-        let players: Vec<Player> = serde_json::from_value(res["response"]["players"].clone())?;
-
-        let player = players.get(0);
-        match player {
-            Some(p) => Ok(Some(p.steamid.clone())),
-            None => Ok(None),
-        }
+        Ok(res.players.into_iter().next().map(|p| p.steamid))
     }
 
     /// Returns game schema, with stats and achievements.
@@ -357,82 +424,35 @@ impl SteamClient {
     /// ```
     #[tracing::instrument(skip(self))]
     pub async fn get_schema_for_game(&self, appid: AppId) -> Result<Option<GameSchema>, SasError> {
-        let url = SteamEndpoint::GetSchemaForGame { appid }.url(&self.api_key);
-
-        let res = self
-            .client
-            .get(url)
-            .send()
-            .instrument(info_span!("API request"))
-            .await?;
-
-        let status = res.status();
-        if !status.is_success() {
-            error!(status = status.as_u16());
-            return Err(SasError::ApiError(format!("Recieved {} from Steam API", status)));
-        }
+        let url = SteamEndpoint::GetSchemaForGame { appid }.url(self)?;
 
-        let res = res.json::<Value>().instrument(info_span!("reading from JSON")).await?;
-
-        match res["game"].as_object() {
-            Some(obj) if obj.is_empty() => Ok(None),
-            Some(obj) => {
-                let schema: GameSchema = match serde_json::from_value(Value::Object(obj.clone())) {
-                    Ok(v) => v,
-                    Err(e) => {
-                        error!(error = ?e);
-                        return Err(SasError::InternalError("Invalid data format".into()));
-                    }
-                };
-
-                Ok(Some(schema))
-            }
-            None => Err(SasError::ApiError("Unexpected response from Steam API".into())),
-        }
+        self.get_checked(url, Schema::into_game_schema).await
     }
 
     /// Returns list of recently played games by given player id.
     ///
+    /// Games of private profiles are [Visibility::Private].
+    ///
     /// # Examples
     /// ```rust
-    /// use steam_api_client::{AppId, SteamClient};
+    /// use steam_api_client::{entity::Visibility, SteamClient};
     ///
     /// # tokio_test::block_on(async {
     /// let client = SteamClient::new();
     /// match client.get_recent_games("playerId").await {
-    ///     Ok(games) => {/*List of games*/},
+    ///     Ok(Visibility::Public(games)) => {/*List of games*/},
+    ///     Ok(Visibility::Private) => {/*Profile is private*/},
     ///     Err(e) => {/*Error*/}
     /// }
     /// # });
     /// ```
     #[tracing::instrument(skip(self))]
-    pub async fn get_recent_games(&self, id: &str) -> Result<Vec<RecentGame>, SasError> {
-        let url = SteamEndpoint::GetRecentlyPlayedGames{ steam_id:id }.url(&self.api_key);
-
-        let res = self
-            .client
-            .get(url)
-            .send()
-            .instrument(info_span!("API request"))
-            .await?;
-
-        let status = res.status();
-        if !status.is_success() {
-            error!(status = status.as_u16());
-            return Err(SasError::ApiError(format!("Recieved {} from Steam API", status)));
-        }
+    pub async fn get_recent_games(&self, id: &str) -> Result<Visibility<Vec<RecentGame>>, SasError> {
+        let url = SteamEndpoint::GetRecentlyPlayedGames{ steam_id:id }.url(self)?;
 
-        let res = res.json::<Value>().instrument(info_span!("reading from JSON")).await?;
+        let res: Games<RecentGame> = self.get_response(url).await?;
 
-        let games: Vec<RecentGame> = match serde_json::from_value(res["response"]["games"].clone()) {
-            Ok(v) => v,
-            Err(e) => {
-                error!(error = ?e);
-                return Err(SasError::InternalError("Invalid data format".into()));
-            }
-        };
-
-        Ok(games)
+        Ok(res.visibility())
     }
 
     /// Validates api key given to the client.
@@ -457,7 +477,7 @@ impl SteamClient {
         self.get_schema_for_game(AppId(400)).await.map(|_e| ())
     }
 
-    /// Returns profile picture data for given player.
+    /// Returns profile picture of given player with its format, `None` when the picture does not exist.
     ///
     /// # Examples
     /// ```rust
@@ -469,23 +489,25 @@ impl SteamClient {
     /// # let player = Player {
     /// # personaname: String::new(),
     /// # steamid: String::new(),
+    /// # avatar: String::new(),
+    /// # avatarmedium: String::new(),
     /// # avatarfull: String::new(),
+    /// # communityvisibilitystate: 3,
     /// # profileurl: String::new(),
     /// # };
     /// match client.get_profile_pic(&player).await {
-    ///     Ok(profile_pic) => {/*Profile pic*/}
+    ///     Ok(Some(profile_pic)) => {/*Profile pic*/}
+    ///     Ok(None) => {/*Missing profile pic*/}
     ///     Err(e) => {/*Error*/}
     /// }
     /// # });
     /// ```
     #[tracing::instrument(skip(self))]
-    pub async fn get_profile_pic(&self, player: &Player) -> Result<Bytes, SasError> {
-        let res = self.client.get(&player.avatarfull).send().instrument(info_span!("Image request")).await?;
-
-        Ok(res.bytes().await?)
+    pub async fn get_profile_pic(&self, player: &Player) -> Result<Option<Image>, SasError> {
+        self.get_optional_image(&player.avatarfull).await
     }
 
-    /// Returns small image capsule for given app.
+    /// Returns small image capsule for given app, `None` when the app does not have the image.
     ///
     /// # Examples
     /// ```rust
@@ -494,18 +516,18 @@ impl SteamClient {
     /// # tokio_test::block_on(async {
     /// let client = SteamClient::new();
     /// match client.get_game_small_capsule(AppId(400)).await {
-    ///     Ok(pic) => {/*Capsule pic*/},
+    ///     Ok(Some(pic)) => {/*Capsule pic*/},
+    ///     Ok(None) => {/*Missing capsule*/},
     ///     Err(e) => {/*Error*/}
     /// }
     /// # });
     /// ```
+    #[deprecated(note = "use `get_app_image` with `AppImage::SmallCapsule`")]
     #[tracing::instrument(skip(self))]
-    pub async fn get_game_small_capsule(&self, appid: AppId) -> Result<Bytes, SasError> {
-        let url = SteamImageEndpoint::SmallCapsule { appid }.url();
-
-        let res = self.client.get(&url).send().instrument(info_span!("Image request")).await?;
+    pub async fn get_game_small_capsule(&self, appid: AppId) -> Result<Option<Bytes>, SasError> {
+        let image = self.get_app_image(appid, AppImage::SmallCapsule).await?;
 
-        Ok(res.bytes().await?)
+        Ok(image.map(|image| image.bytes))
     }
 
     /// Returns library image capsule for given app.
@@ -522,17 +544,156 @@ impl SteamClient {
     /// }
     /// # });
     /// ```
+    #[deprecated(note = "use `get_app_image` with `AppImage::LibraryCapsule`")]
     #[tracing::instrument(skip(self))]
     pub async fn get_game_library_capsule(&self, appid: AppId) -> Result<Option<Bytes>, SasError> {
-        let url = SteamImageEndpoint::LibraryCapsule { appid }.url();
+        let image = self.get_app_image(appid, AppImage::LibraryCapsule).await?;
 
-        let res = self.client.get(&url).send().instrument(info_span!("Image request")).await?;
+        Ok(image.map(|image| image.bytes))
+    }
 
-        if res.status() == StatusCode::NOT_FOUND {
-            return Ok(None);
+    /// Sends GET request to given image url and reads the body with its content type,
+    /// returns `None` for missing images.
+    ///
+    /// Fails for server errors and responses which are not images.
+    async fn get_optional_image(&self, url: &str) -> Result<Option<Image>, SasError> {
+        if let Some(cache) = &self.image_cache {
+            return cache.get(&self.client, self.diagnostics.as_ref(), url).await;
         }
 
-        Ok(Some(res.bytes().await?))
+        let res = self.client.get(url).send().instrument(info_span!("Image request")).await?;
+
+        let capture = self.capture(&res);
+        let res = match image::check_image_status(res, &capture).await? {
+            Some(res) => res,
+            None => return Ok(None),
+        };
+
+        let content_type = image::content_type(&res);
+        image::read_image(res.bytes().await?, content_type).map(Some)
+    }
+
+    /// Sends GET request to given url and reads the body as XML.
+    async fn get_xml<T: DeserializeOwned>(&self, url: String) -> Result<T, SasError> {
+        let endpoint = endpoint_path(&url);
+
+        let res = self
+            .client
+            .get(url)
+            .send()
+            .instrument(info_span!("API request"))
+            .await?;
+
+        let capture = self.capture(&res);
+        let res = check_status(res, &capture).await?;
+
+        let body = res.text().instrument(info_span!("reading from XML")).await?;
+
+        match quick_xml::de::from_str(&body) {
+            Ok(v) => Ok(v),
+            Err(e) => {
+                error!(error = ?e);
+                let body = body.as_bytes();
+                Err(attach(&capture, SasError::decode(&endpoint, e, body), body).await)
+            }
+        }
+    }
+
+    /// Sends GET request to given API url and deserializes the body directly into given type.
+    async fn get_typed<T: DeserializeOwned>(&self, url: String) -> Result<T, SasError> {
+        self.get_checked(url, Ok).await
+    }
+
+    /// Sends GET request to given API url, deserializes the body and converts it with given function.
+    ///
+    /// Malformed bodies are decode errors with given message, failures reported by Steam are returned as they are.
+    async fn get_checked<T: DeserializeOwned, R>(
+        &self,
+        url: String,
+        convert: impl FnOnce(T) -> Result<R, Rejected>,
+    ) -> Result<R, SasError> {
+        let endpoint = endpoint_path(&url);
+
+        let res = self
+            .client
+            .get(url)
+            .send()
+            .instrument(info_span!("API request"))
+            .await?;
+
+        let capture = self.capture(&res);
+        let res = check_status(res, &capture).await?;
+
+        let body = res.bytes().instrument(info_span!("reading from JSON")).await?;
+
+        let res = from_slice(&endpoint, &capture, &body).await?;
+        let error = match convert(res) {
+            Ok(res) => return Ok(res),
+            Err(Rejected::Malformed(msg)) => SasError::decode(&endpoint, msg, &body),
+            Err(Rejected::Failed(e)) => e,
+        };
+
+        Err(attach(&capture, error, &body).await)
+    }
+
+    /// Sends GET request to given API url and deserializes content of the `response` object.
+    async fn get_response<T: DeserializeOwned>(&self, url: String) -> Result<T, SasError> {
+        let res: Envelope<T> = self.get_typed(url).await?;
+
+        Ok(res.response)
+    }
+
+    /// Captures response details for diagnostics, when enabled.
+    fn capture(&self, res: &reqwest::Response) -> Option<Capture> {
+        self.diagnostics.as_ref().map(|diagnostics| diagnostics.capture(res))
+    }
+}
+
+/// Returns successful response, error read from status and body otherwise.
+pub(crate) async fn check_status(res: reqwest::Response, capture: &Option<Capture>) -> Result<reqwest::Response, SasError> {
+    let status = res.status();
+    if status.is_success() {
+        return Ok(res);
+    }
+
+    error!(status = status.as_u16());
+
+    // Steam sends the delay in seconds
+    let retry_after = res
+        .headers()
+        .get(RETRY_AFTER)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.parse().ok())
+        .map(Duration::from_secs);
+
+    // body of failed response tells the error apart and goes into diagnostics
+    let body = res.bytes().await.unwrap_or_default();
+
+    Err(attach(capture, SasError::from_status(status, retry_after, &body), &body).await)
+}
+
+/// Returns path of given url, identifying the endpoint in errors without exposing credentials from query.
+fn endpoint_path(url: &str) -> String {
+    match reqwest::Url::parse(url) {
+        Ok(url) => url.path().to_owned(),
+        Err(_) => String::new(),
+    }
+}
+
+/// Deserializes given JSON body.
+async fn from_slice<T: DeserializeOwned>(endpoint: &str, capture: &Option<Capture>, body: &[u8]) -> Result<T, SasError> {
+    match serde_json::from_slice(body) {
+        Ok(v) => Ok(v),
+        Err(e) => {
+            error!(error = ?e);
+            Err(attach(capture, SasError::decode(endpoint, e, body), body).await)
+        }
+    }
+}
+
+impl Default for SteamClient {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
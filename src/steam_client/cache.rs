@@ -0,0 +1,335 @@
+use std::{
+    collections::HashSet,
+    io,
+    path::{Path, PathBuf},
+    sync::atomic::{AtomicU64, Ordering},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use bytes::Bytes;
+use reqwest::{
+    header::{HeaderMap, CACHE_CONTROL, ETAG, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED},
+    StatusCode,
+};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use tracing::{info_span, warn};
+use tracing_futures::Instrument;
+
+use crate::{
+    entity::{Image, ImageFormat},
+    error::SasError,
+};
+
+use super::{
+    diagnostics::Diagnostics,
+    image::{check_image_status, content_type, read_image},
+};
+
+/// Freshness of images downloaded without `Cache-Control: max-age`, in milliseconds.
+const DEFAULT_MAX_AGE: u64 = 24 * 60 * 60 * 1000;
+
+/// Age after which temporary files and data files without metadata are left over from interrupted writes.
+const STALE_FILE_AGE: Duration = Duration::from_secs(10 * 60);
+
+/// Image cache directory, entries are keyed by hash of the image url.
+///
+/// Each entry is stored as image data file and `.meta` file with validators used for revalidation.
+pub(crate) struct ImageCache {
+    dir: PathBuf,
+    max_size: u64,
+}
+
+/// Metadata of cached image.
+#[derive(Clone, Serialize, Deserialize, Debug)]
+struct CacheMeta {
+    url: String,
+    etag: Option<String>,
+    last_modified: Option<String>,
+    content_type: Option<String>,
+    size: u64,
+    last_used: u64,
+    /// Time until which the image is used without revalidation, entries written without it are stale
+    #[serde(default)]
+    expires: u64,
+}
+
+/// Cached image on disk, with the image if it was just downloaded.
+struct CacheEntry {
+    meta: CacheMeta,
+    path: PathBuf,
+    image: Option<Image>,
+}
+
+impl ImageCache {
+    pub fn new(dir: PathBuf, max_size: u64) -> Self {
+        Self { dir, max_size }
+    }
+
+    /// Returns image with given url, downloading it only when cached copy is missing or changed.
+    pub async fn get(
+        &self,
+        client: &reqwest::Client,
+        diagnostics: Option<&Diagnostics>,
+        url: &str,
+    ) -> Result<Option<Image>, SasError> {
+        let entry = match self.fetch(client, diagnostics, url, true).await? {
+            Some(entry) => entry,
+            None => return Ok(None),
+        };
+
+        if let Some(image) = entry.image {
+            return Ok(Some(image));
+        }
+
+        match tokio::fs::read(&entry.path).await {
+            Ok(bytes) => {
+                let bytes: Bytes = bytes.into();
+                Ok(Some(Image {
+                    format: ImageFormat::detect(&bytes),
+                    bytes,
+                    content_type: entry.meta.content_type,
+                }))
+            }
+            // evicted by concurrent request after revalidation
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {
+                Ok(self.fetch(client, diagnostics, url, false).await?.and_then(|entry| entry.image))
+            }
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Returns path of cached image with given url, downloading it only when cached copy is missing or changed.
+    pub async fn get_path(
+        &self,
+        client: &reqwest::Client,
+        diagnostics: Option<&Diagnostics>,
+        url: &str,
+    ) -> Result<Option<PathBuf>, SasError> {
+        Ok(self.fetch(client, diagnostics, url, true).await?.map(|entry| entry.path))
+    }
+
+    /// Returns cached entry, revalidated once it is no longer fresh, when `revalidate` is set,
+    /// otherwise the image is always downloaded.
+    ///
+    /// Stale entry is returned when Steam CDN cannot be reached or fails.
+    async fn fetch(
+        &self,
+        client: &reqwest::Client,
+        diagnostics: Option<&Diagnostics>,
+        url: &str,
+        revalidate: bool,
+    ) -> Result<Option<CacheEntry>, SasError> {
+        let key = cache_key(url);
+        let path = self.dir.join(&key);
+        let meta_path = meta_path(&path);
+
+        // cached entry is used only when the data is still there
+        let cached = match read_meta(&meta_path).await {
+            Some(meta) if revalidate && meta.url == url && tokio::fs::metadata(&path).await.is_ok() => Some(meta),
+            _ => None,
+        };
+
+        if let Some(meta) = cached.as_ref().filter(|meta| meta.expires > now()) {
+            return touch(meta.clone(), path, &meta_path).await;
+        }
+
+        let mut req = client.get(url);
+        if let Some(meta) = &cached {
+            if let Some(etag) = &meta.etag {
+                req = req.header(IF_NONE_MATCH, etag);
+            }
+            if let Some(last_modified) = &meta.last_modified {
+                req = req.header(IF_MODIFIED_SINCE, last_modified);
+            }
+        }
+
+        let res = match req.send().instrument(info_span!("Image request")).await {
+            Ok(res) => res,
+            Err(e) => match cached {
+                Some(meta) => {
+                    warn!(error = ?e, "using stale cached image");
+                    return touch(meta, path, &meta_path).await;
+                }
+                None => return Err(e.into()),
+            },
+        };
+
+        if let Some(mut meta) = cached {
+            let status = res.status();
+            if status == StatusCode::NOT_MODIFIED {
+                meta.expires = expires(res.headers());
+                return touch(meta, path, &meta_path).await;
+            }
+            if status.is_server_error() {
+                warn!(status = status.as_u16(), "using stale cached image");
+                return touch(meta, path, &meta_path).await;
+            }
+        }
+
+        let capture = diagnostics.map(|diagnostics| diagnostics.capture(&res));
+        let res = match check_image_status(res, &capture).await? {
+            Some(res) => res,
+            None => {
+                remove_entry(&path).await;
+                return Ok(None);
+            }
+        };
+
+        let header = |name| res.headers().get(name).and_then(|v| v.to_str().ok()).map(|v| v.to_owned());
+        let etag = header(ETAG);
+        let last_modified = header(LAST_MODIFIED);
+        let content_type = content_type(&res);
+        let expires = expires(res.headers());
+
+        let image = read_image(res.bytes().await?, content_type)?;
+
+        let meta = CacheMeta {
+            url: url.to_owned(),
+            etag,
+            last_modified,
+            content_type: image.content_type.clone(),
+            size: image.bytes.len() as u64,
+            last_used: now(),
+            expires,
+        };
+
+        tokio::fs::create_dir_all(&self.dir).await?;
+        write_atomic(&path, &image.bytes).await?;
+        write_meta(&meta_path, &meta).await?;
+
+        if let Err(e) = self.evict(&path).await {
+            warn!(error = ?e, "image cache eviction failed");
+        }
+
+        Ok(Some(CacheEntry {
+            meta,
+            path,
+            image: Some(image),
+        }))
+    }
+
+    /// Removes least recently used entries until the cache fits into its size, keeping the entry at `keep`.
+    ///
+    /// Entries with unreadable metadata and files left over from interrupted writes are removed as well.
+    async fn evict(&self, keep: &Path) -> io::Result<()> {
+        let mut entries = Vec::new();
+        let mut leftovers = Vec::new();
+        let mut dir = tokio::fs::read_dir(&self.dir).await?;
+        while let Some(file) = dir.next_entry().await? {
+            let path = file.path();
+            match path.extension() {
+                Some(ext) if ext == "meta" => match read_meta(&path).await {
+                    Some(meta) => entries.push((path.with_extension(""), meta)),
+                    None => remove_entry(&path.with_extension("")).await,
+                },
+                _ if is_stale(&file).await => leftovers.push(path),
+                _ => {}
+            }
+        }
+
+        // data files are written before their metadata, so only old ones are left over
+        let cached: HashSet<&PathBuf> = entries.iter().map(|(path, _)| path).collect();
+        for path in leftovers.iter().filter(|path| !cached.contains(path)) {
+            let _ = tokio::fs::remove_file(path).await;
+        }
+
+        let mut total: u64 = entries.iter().map(|(_, meta)| meta.size).sum();
+        if total <= self.max_size {
+            return Ok(());
+        }
+
+        entries.sort_by_key(|(_, meta)| meta.last_used);
+        for (path, meta) in entries.into_iter().filter(|(path, _)| path != keep) {
+            if total <= self.max_size {
+                break;
+            }
+            remove_entry(&path).await;
+            total -= meta.size;
+        }
+
+        Ok(())
+    }
+}
+
+fn cache_key(url: &str) -> String {
+    Sha256::digest(url.as_bytes()).iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn meta_path(path: &Path) -> PathBuf {
+    path.with_extension("meta")
+}
+
+/// Returns time until which response with given headers is fresh, following `Cache-Control`.
+fn expires(headers: &HeaderMap) -> u64 {
+    let directives = headers.get_all(CACHE_CONTROL).iter().filter_map(|value| value.to_str().ok());
+
+    let mut max_age = DEFAULT_MAX_AGE;
+    for directive in directives.flat_map(|value| value.split(',')).map(str::trim) {
+        if directive == "no-cache" || directive == "no-store" {
+            return 0;
+        }
+        if let Some(seconds) = directive.strip_prefix("max-age=").and_then(|s| s.parse::<u64>().ok()) {
+            max_age = seconds.saturating_mul(1000);
+        }
+    }
+
+    now().saturating_add(max_age)
+}
+
+/// Returns `true` for files not modified for [STALE_FILE_AGE].
+async fn is_stale(file: &tokio::fs::DirEntry) -> bool {
+    let modified = match file.metadata().await.and_then(|metadata| metadata.modified()) {
+        Ok(modified) => modified,
+        Err(_) => return false,
+    };
+
+    modified.elapsed().is_ok_and(|age| age > STALE_FILE_AGE)
+}
+
+/// Marks cached entry as used and returns it.
+async fn touch(mut meta: CacheMeta, path: PathBuf, meta_path: &Path) -> Result<Option<CacheEntry>, SasError> {
+    meta.last_used = now();
+    write_meta(meta_path, &meta).await?;
+
+    Ok(Some(CacheEntry { meta, path, image: None }))
+}
+
+fn now() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_millis() as u64).unwrap_or(0)
+}
+
+async fn read_meta(meta_path: &Path) -> Option<CacheMeta> {
+    let meta = tokio::fs::read(meta_path).await.ok()?;
+    serde_json::from_slice(&meta).ok()
+}
+
+async fn write_meta(meta_path: &Path, meta: &CacheMeta) -> Result<(), SasError> {
+    write_atomic(meta_path, &serde_json::to_vec(meta).map_err(io::Error::from)?).await?;
+    Ok(())
+}
+
+/// Writes into temporary file first, so readers never see partial files.
+///
+/// Temporary file is unique to each write, concurrent writers of the same entry would interleave otherwise.
+async fn write_atomic(path: &Path, data: &[u8]) -> io::Result<()> {
+    static WRITES: AtomicU64 = AtomicU64::new(0);
+
+    let mut tmp = path.as_os_str().to_owned();
+    tmp.push(format!(".{}-{}.tmp", std::process::id(), WRITES.fetch_add(1, Ordering::Relaxed)));
+
+    let written = match tokio::fs::write(&tmp, data).await {
+        Ok(()) => tokio::fs::rename(&tmp, path).await,
+        Err(e) => Err(e),
+    };
+    if written.is_err() {
+        let _ = tokio::fs::remove_file(&tmp).await;
+    }
+
+    written
+}
+
+async fn remove_entry(path: &Path) {
+    let _ = tokio::fs::remove_file(meta_path(path)).await;
+    let _ = tokio::fs::remove_file(path).await;
+}
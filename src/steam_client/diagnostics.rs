@@ -0,0 +1,110 @@
+use std::{
+    fmt::Write,
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use reqwest::{header::HeaderMap, Response, StatusCode};
+use tracing::warn;
+
+use crate::{
+    auth::redact_url,
+    error::{ResponseDiagnostics, SasError},
+};
+
+/// Maximum length of body included in [ResponseDiagnostics].
+const DIAGNOSTICS_BODY_LEN: usize = 4096;
+
+/// Diagnostics settings of the client.
+#[derive(Default)]
+pub(crate) struct Diagnostics {
+    pub dump_dir: Option<PathBuf>,
+}
+
+impl Diagnostics {
+    /// Captures response details, before the body is read.
+    pub fn capture(&self, res: &Response) -> Capture {
+        let mut url = res.url().clone();
+        redact_url(&mut url);
+
+        Capture {
+            endpoint: url.path().to_owned(),
+            url: url.to_string(),
+            status: res.status(),
+            headers: res.headers().clone(),
+            dump_dir: self.dump_dir.clone(),
+        }
+    }
+}
+
+/// Details of response, attached to decode and status errors.
+pub(crate) struct Capture {
+    endpoint: String,
+    url: String,
+    status: StatusCode,
+    headers: HeaderMap,
+    dump_dir: Option<PathBuf>,
+}
+
+impl Capture {
+    /// Attaches diagnostics to errors caused by the response, dumping the full response when dump directory is set.
+    pub async fn attach(&self, mut error: SasError, body: &[u8]) -> SasError {
+        if error.diagnostics_mut().is_none() {
+            return error;
+        }
+
+        let dump_path = match &self.dump_dir {
+            Some(dir) => self.dump(dir, body).await,
+            None => None,
+        };
+        let body = &body[..body.len().min(DIAGNOSTICS_BODY_LEN)];
+
+        if let Some(diagnostics) = error.diagnostics_mut() {
+            *diagnostics = Some(Box::new(ResponseDiagnostics {
+                url: self.url.clone(),
+                status: self.status,
+                headers: self.headers.clone(),
+                body: String::from_utf8_lossy(body).into_owned(),
+                dump_path,
+            }));
+        }
+
+        error
+    }
+
+    /// Writes the full response into given directory, returning path of the file.
+    async fn dump(&self, dir: &Path, body: &[u8]) -> Option<PathBuf> {
+        let millis = SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_millis()).unwrap_or(0);
+        let name = self.endpoint.trim_matches('/').replace('/', "_");
+        let path = dir.join(format!("{}-{}.txt", millis, name));
+
+        let mut head = format!("{}\n{}\n", self.url, self.status);
+        for (name, value) in &self.headers {
+            let _ = writeln!(head, "{}: {}", name, value.to_str().unwrap_or("<binary>"));
+        }
+        head.push('\n');
+
+        let mut data = head.into_bytes();
+        data.extend_from_slice(body);
+
+        let written = match tokio::fs::create_dir_all(dir).await {
+            Ok(_) => tokio::fs::write(&path, data).await,
+            Err(e) => Err(e),
+        };
+        match written {
+            Ok(_) => Some(path),
+            Err(e) => {
+                warn!(error = ?e, "could not write response dump");
+                None
+            }
+        }
+    }
+}
+
+/// Attaches diagnostics to errors caused by the response, when diagnostics are enabled.
+pub(crate) async fn attach(capture: &Option<Capture>, error: SasError, body: &[u8]) -> SasError {
+    match capture {
+        Some(capture) => capture.attach(error, body).await,
+        None => error,
+    }
+}
@@ -0,0 +1,126 @@
+use crate::{
+    entity::{ApiInterface, EndpointStatus, EndpointSupport, ServerInfo},
+    error::SasError,
+};
+
+use super::{
+    endpoint::{SteamEndpoint, RESOURCES},
+    host::Host,
+    response::ApiList,
+    SteamClient,
+};
+
+impl SteamClient {
+    /// Returns list of interfaces and methods available to the client.
+    ///
+    /// Methods needing api key are included only when the client has one.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use steam_api_client::SteamClient;
+    ///
+    /// # tokio_test::block_on(async {
+    /// let client = SteamClient::new();
+    /// match client.get_supported_api_list().await {
+    ///     Ok(interfaces) => {/*List of interfaces*/},
+    ///     Err(e) => {/*Error*/}
+    /// }
+    /// # });
+    /// ```
+    #[tracing::instrument(skip(self))]
+    pub async fn get_supported_api_list(&self) -> Result<Vec<ApiInterface>, SasError> {
+        self.supported_api_list(Host::Api).await
+    }
+
+    /// Returns Web API server time.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use steam_api_client::SteamClient;
+    ///
+    /// # tokio_test::block_on(async {
+    /// let client = SteamClient::new();
+    /// match client.get_server_info().await {
+    ///     Ok(info) => {/*Server time*/},
+    ///     Err(e) => {/*Error*/}
+    /// }
+    /// # });
+    /// ```
+    #[tracing::instrument(skip(self))]
+    pub async fn get_server_info(&self) -> Result<ServerInfo, SasError> {
+        let url = SteamEndpoint::GetServerInfo.url(self)?;
+
+        self.get_typed(url).await
+    }
+
+    /// Checks methods used by this library against methods available to the client.
+    ///
+    /// Publisher methods are checked only when publisher key is set.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use steam_api_client::SteamClient;
+    /// use steam_api_client::entity::EndpointStatus;
+    ///
+    /// # tokio_test::block_on(async {
+    /// let client = SteamClient::new();
+    /// match client.check_endpoints().await {
+    ///     Ok(endpoints) => {
+    ///         for endpoint in endpoints.iter().filter(|e| e.status != EndpointStatus::Supported) {
+    ///             /*Missing or outdated endpoint*/
+    ///         }
+    ///     },
+    ///     Err(e) => {/*Error*/}
+    /// }
+    /// # });
+    /// ```
+    #[tracing::instrument(skip(self))]
+    pub async fn check_endpoints(&self) -> Result<Vec<EndpointSupport>, SasError> {
+        let mut hosts = vec![(Host::Api, self.supported_api_list(Host::Api).await?)];
+        if self.publisher_key.is_some() {
+            hosts.push((Host::Partner, self.supported_api_list(Host::Partner).await?));
+        }
+
+        let mut report = Vec::new();
+        for (host, interfaces) in &hosts {
+            for resource in RESOURCES.iter().filter(|r| r.host == *host) {
+                let versions = interfaces
+                    .iter()
+                    .filter(|i| i.name == resource.interface)
+                    .flat_map(|i| i.methods.iter())
+                    .filter(|m| m.name == resource.method)
+                    .map(|m| m.version);
+
+                let mut has_version = false;
+                let mut latest = None;
+                for version in versions {
+                    has_version |= version == resource.version;
+                    latest = latest.max(Some(version));
+                }
+
+                let status = match latest {
+                    Some(latest) if latest > resource.version => EndpointStatus::Outdated { latest },
+                    _ if has_version => EndpointStatus::Supported,
+                    _ => EndpointStatus::Missing,
+                };
+
+                report.push(EndpointSupport {
+                    interface: resource.interface.to_owned(),
+                    method: resource.method.to_owned(),
+                    version: resource.version,
+                    status,
+                });
+            }
+        }
+
+        Ok(report)
+    }
+
+    async fn supported_api_list(&self, host: Host) -> Result<Vec<ApiInterface>, SasError> {
+        let url = SteamEndpoint::GetSupportedApiList { host }.url(self)?;
+
+        let res: ApiList = self.get_typed(url).await?;
+
+        Ok(res.apilist.interfaces)
+    }
+}
@@ -0,0 +1,86 @@
+use crate::{
+    entity::{AssetClass, AssetClassInfo, AssetPrices},
+    error::SasError,
+    AppId,
+};
+
+use super::{
+    endpoint::SteamEndpoint,
+    response::{AssetClasses, Economy, Prices},
+    SteamClient,
+};
+
+impl SteamClient {
+    /// Returns in-game store prices for given app.
+    ///
+    /// Prices can be limited to single currency, names can be localized.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use steam_api_client::{AppId, SteamClient};
+    ///
+    /// # tokio_test::block_on(async {
+    /// let client = SteamClient::new();
+    /// match client.get_asset_prices(AppId(440), Some("USD"), None).await {
+    ///     Ok(prices) => {/*Store prices*/},
+    ///     Err(e) => {/*Error*/}
+    /// }
+    /// # });
+    /// ```
+    #[tracing::instrument(skip(self))]
+    pub async fn get_asset_prices(
+        &self,
+        appid: AppId,
+        currency: Option<&str>,
+        lang: Option<&str>,
+    ) -> Result<AssetPrices, SasError> {
+        let url = SteamEndpoint::GetAssetPrices { appid, currency, lang }.url(self)?;
+
+        self.get_checked(url, |res: Economy<Prices>| res.into_result()?.into_prices())
+            .await
+    }
+
+    /// Returns descriptions of given asset classes, in the order of given classes.
+    ///
+    /// Classes unknown to Steam are left out.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use steam_api_client::{AppId, SteamClient};
+    /// use steam_api_client::entity::AssetClass;
+    ///
+    /// # tokio_test::block_on(async {
+    /// let client = SteamClient::new();
+    /// let classes = [AssetClass { classid: 195151, instanceid: None }];
+    /// match client.get_asset_class_info(AppId(440), &classes, Some("en")).await {
+    ///     Ok(info) => {/*Class descriptions*/},
+    ///     Err(e) => {/*Error*/}
+    /// }
+    /// # });
+    /// ```
+    #[tracing::instrument(skip(self))]
+    pub async fn get_asset_class_info(
+        &self,
+        appid: AppId,
+        classes: &[AssetClass],
+        lang: Option<&str>,
+    ) -> Result<Vec<AssetClassInfo>, SasError> {
+        let url = SteamEndpoint::GetAssetClassInfo { appid, lang, classes }.url(self)?;
+
+        self.get_checked(url, |res: Economy<AssetClasses>| {
+            let mut found = res.into_result()?;
+
+            // classes are keyed by `classid_instanceid` when instance is given, by `classid` otherwise
+            Ok(classes
+                .iter()
+                .filter_map(|class| {
+                    class
+                        .instanceid
+                        .and_then(|instanceid| found.remove(&format!("{}_{}", class.classid, instanceid)))
+                        .or_else(|| found.remove(&class.classid.to_string()))
+                })
+                .collect())
+        })
+        .await
+    }
+}
@@ -1,7 +1,17 @@
-use crate::AppId;
+use std::{borrow::Cow, fmt::Display};
+
+use reqwest::Url;
+
+use crate::{
+    entity::{AppImage, AssetClass, LeaderboardRange, ServerFilter, TradeHistoryQuery, TradeOffersQuery},
+    error::SasError,
+    AppId, SteamId,
+};
+
+use super::{host::Host, SteamClient};
 
-const BASE_URL: &str = "https://api.steampowered.com";
 const BASE_IMAGE_URL: &str = "https://cdn.cloudflare.steamstatic.com/steam/apps";
+const BASE_ICON_URL: &str = "https://cdn.cloudflare.steamstatic.com/steamcommunity/public/images/apps";
 
 pub enum SteamEndpoint<'a> {
     GetOwnedGames {
@@ -29,71 +39,754 @@ pub enum SteamEndpoint<'a> {
     GetRecentlyPlayedGames {
         steam_id: &'a str,
     },
+    GetAssetPrices {
+        appid: AppId,
+        currency: Option<&'a str>,
+        lang: Option<&'a str>,
+    },
+    GetAssetClassInfo {
+        appid: AppId,
+        lang: Option<&'a str>,
+        classes: &'a [AssetClass],
+    },
+    GetTradeOffers {
+        query: &'a TradeOffersQuery,
+    },
+    GetTradeOffer {
+        tradeofferid: &'a str,
+        lang: Option<&'a str>,
+    },
+    GetTradeOffersSummary {
+        time_last_visit: Option<u64>,
+    },
+    GetTradeHistory {
+        query: &'a TradeHistoryQuery,
+    },
+    GetTradeHoldDurations {
+        steam_id: &'a str,
+        trade_offer_access_token: Option<&'a str>,
+    },
+    CheckAppOwnership {
+        steam_id: &'a str,
+        appid: AppId,
+    },
+    GetPublisherAppOwnership {
+        steam_id: &'a str,
+    },
+    AuthenticateUserTicket {
+        appid: AppId,
+        ticket: &'a [u8],
+        identity: Option<&'a str>,
+    },
+    GetAppBetas {
+        appid: AppId,
+    },
+    GetLeaderboardsForGame {
+        appid: AppId,
+    },
+    GetLeaderboardEntries {
+        appid: AppId,
+        leaderboard_id: u64,
+        range: LeaderboardRange<'a>,
+    },
+    GetServerList {
+        filter: &'a ServerFilter,
+        limit: Option<u32>,
+    },
+    GetServersAtAddress {
+        addr: &'a str,
+    },
+    GetSupportedApiList {
+        host: Host,
+    },
+    GetServerInfo,
+    GetWishlist {
+        steam_id: &'a str,
+    },
+    GetWishlistItemCount {
+        steam_id: &'a str,
+    },
+    GetUserGroupList {
+        steam_id: &'a str,
+    },
+    IsPlayingSharedGame {
+        steam_id: SteamId,
+        appid: AppId,
+    },
+    GetFamilyGroupForUser {
+        steam_id: SteamId,
+    },
+    GetFamilyGroup {
+        family_group_id: &'a str,
+    },
+    GetSharedLibraryApps {
+        family_group_id: &'a str,
+        include_own: bool,
+        lang: Option<&'a str>,
+    },
+    GetProfileItemsEquipped {
+        steam_id: SteamId,
+        lang: Option<&'a str>,
+    },
+    GetAnimatedAvatar {
+        steam_id: SteamId,
+        lang: Option<&'a str>,
+    },
+    GetAvatarFrame {
+        steam_id: SteamId,
+        lang: Option<&'a str>,
+    },
+    GetMiniProfileBackground {
+        steam_id: SteamId,
+        lang: Option<&'a str>,
+    },
+    GetProfileBackground {
+        steam_id: SteamId,
+        lang: Option<&'a str>,
+    },
+}
+
+/// Credentials an endpoint needs.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Credential {
+    /// Public endpoint
+    None,
+    /// Endpoint needs api key
+    ApiKey,
+    /// Endpoint uses api key or access token when available
+    Optional,
+    /// Endpoint accepts either, api key is preferred
+    Any,
+    /// Endpoint needs access token
+    AccessToken,
+    /// Endpoint needs publisher key
+    PublisherKey,
 }
 
+/// Method of Steam Web API, with the host serving it.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Resource {
+    pub host: Host,
+    pub interface: &'static str,
+    pub method: &'static str,
+    pub version: u32,
+}
+
+impl Resource {
+    const fn new(host: Host, interface: &'static str, method: &'static str, version: u32) -> Self {
+        Self {
+            host,
+            interface,
+            method,
+            version,
+        }
+    }
+
+    /// Returns path of the method, e.g. `/ISteamUser/GetPlayerSummaries/v2/`.
+    pub fn path(&self) -> String {
+        format!("/{}/{}/v{}/", self.interface, self.method, self.version)
+    }
+}
+
+const GET_OWNED_GAMES: Resource = Resource::new(Host::Api, "IPlayerService", "GetOwnedGames", 1);
+const GET_PLAYER_ACHIEVEMENTS: Resource = Resource::new(Host::Api, "ISteamUserStats", "GetPlayerAchievements", 1);
+const RESOLVE_VANITY_URL: Resource = Resource::new(Host::Api, "ISteamUser", "ResolveVanityURL", 1);
+const GET_PLAYER_SUMMARIES: Resource = Resource::new(Host::Api, "ISteamUser", "GetPlayerSummaries", 2);
+const GET_GLOBAL_ACHIEVEMENT_PERCENTAGES_FOR_APP: Resource =
+    Resource::new(Host::Api, "ISteamUserStats", "GetGlobalAchievementPercentagesForApp", 2);
+const GET_SCHEMA_FOR_GAME: Resource = Resource::new(Host::Api, "ISteamUserStats", "GetSchemaForGame", 2);
+const GET_RECENTLY_PLAYED_GAMES: Resource = Resource::new(Host::Api, "IPlayerService", "GetRecentlyPlayedGames", 1);
+const GET_ASSET_PRICES: Resource = Resource::new(Host::Api, "ISteamEconomy", "GetAssetPrices", 1);
+const GET_ASSET_CLASS_INFO: Resource = Resource::new(Host::Api, "ISteamEconomy", "GetAssetClassInfo", 1);
+const GET_TRADE_OFFERS: Resource = Resource::new(Host::Api, "IEconService", "GetTradeOffers", 1);
+const GET_TRADE_OFFER: Resource = Resource::new(Host::Api, "IEconService", "GetTradeOffer", 1);
+const GET_TRADE_OFFERS_SUMMARY: Resource = Resource::new(Host::Api, "IEconService", "GetTradeOffersSummary", 1);
+const GET_TRADE_HISTORY: Resource = Resource::new(Host::Api, "IEconService", "GetTradeHistory", 1);
+const GET_TRADE_HOLD_DURATIONS: Resource = Resource::new(Host::Api, "IEconService", "GetTradeHoldDurations", 1);
+const CHECK_APP_OWNERSHIP: Resource = Resource::new(Host::Partner, "ISteamUser", "CheckAppOwnership", 4);
+const GET_PUBLISHER_APP_OWNERSHIP: Resource =
+    Resource::new(Host::Partner, "ISteamUser", "GetPublisherAppOwnership", 3);
+const AUTHENTICATE_USER_TICKET: Resource =
+    Resource::new(Host::Partner, "ISteamUserAuth", "AuthenticateUserTicket", 1);
+const GET_APP_BETAS: Resource = Resource::new(Host::Partner, "ISteamApps", "GetAppBetas", 1);
+const GET_LEADERBOARDS_FOR_GAME: Resource =
+    Resource::new(Host::Partner, "ISteamLeaderboards", "GetLeaderboardsForGame", 2);
+const GET_LEADERBOARD_ENTRIES: Resource =
+    Resource::new(Host::Partner, "ISteamLeaderboards", "GetLeaderboardEntries", 1);
+const GET_SERVER_LIST: Resource = Resource::new(Host::Api, "IGameServersService", "GetServerList", 1);
+const GET_SERVERS_AT_ADDRESS: Resource = Resource::new(Host::Api, "ISteamApps", "GetServersAtAddress", 1);
+const GET_SUPPORTED_API_LIST: Resource = Resource::new(Host::Api, "ISteamWebAPIUtil", "GetSupportedAPIList", 1);
+const GET_PARTNER_SUPPORTED_API_LIST: Resource =
+    Resource::new(Host::Partner, "ISteamWebAPIUtil", "GetSupportedAPIList", 1);
+const GET_SERVER_INFO: Resource = Resource::new(Host::Api, "ISteamWebAPIUtil", "GetServerInfo", 1);
+const GET_WISHLIST: Resource = Resource::new(Host::Api, "IWishlistService", "GetWishlist", 1);
+const GET_WISHLIST_ITEM_COUNT: Resource = Resource::new(Host::Api, "IWishlistService", "GetWishlistItemCount", 1);
+const GET_USER_GROUP_LIST: Resource = Resource::new(Host::Api, "ISteamUser", "GetUserGroupList", 1);
+const IS_PLAYING_SHARED_GAME: Resource = Resource::new(Host::Api, "IPlayerService", "IsPlayingSharedGame", 1);
+const GET_FAMILY_GROUP_FOR_USER: Resource =
+    Resource::new(Host::Api, "IFamilyGroupsService", "GetFamilyGroupForUser", 1);
+const GET_FAMILY_GROUP: Resource = Resource::new(Host::Api, "IFamilyGroupsService", "GetFamilyGroup", 1);
+const GET_SHARED_LIBRARY_APPS: Resource = Resource::new(Host::Api, "IFamilyGroupsService", "GetSharedLibraryApps", 1);
+const GET_PROFILE_ITEMS_EQUIPPED: Resource =
+    Resource::new(Host::Api, "IPlayerService", "GetProfileItemsEquipped", 1);
+const GET_ANIMATED_AVATAR: Resource = Resource::new(Host::Api, "IPlayerService", "GetAnimatedAvatar", 1);
+const GET_AVATAR_FRAME: Resource = Resource::new(Host::Api, "IPlayerService", "GetAvatarFrame", 1);
+const GET_MINI_PROFILE_BACKGROUND: Resource =
+    Resource::new(Host::Api, "IPlayerService", "GetMiniProfileBackground", 1);
+const GET_PROFILE_BACKGROUND: Resource = Resource::new(Host::Api, "IPlayerService", "GetProfileBackground", 1);
+
+/// Every method used by [SteamEndpoint], checked against all variants by `resources_match_endpoints` test.
+pub const RESOURCES: &[Resource] = &[
+    GET_OWNED_GAMES,
+    GET_PLAYER_ACHIEVEMENTS,
+    RESOLVE_VANITY_URL,
+    GET_PLAYER_SUMMARIES,
+    GET_GLOBAL_ACHIEVEMENT_PERCENTAGES_FOR_APP,
+    GET_SCHEMA_FOR_GAME,
+    GET_RECENTLY_PLAYED_GAMES,
+    GET_ASSET_PRICES,
+    GET_ASSET_CLASS_INFO,
+    GET_TRADE_OFFERS,
+    GET_TRADE_OFFER,
+    GET_TRADE_OFFERS_SUMMARY,
+    GET_TRADE_HISTORY,
+    GET_TRADE_HOLD_DURATIONS,
+    CHECK_APP_OWNERSHIP,
+    GET_PUBLISHER_APP_OWNERSHIP,
+    AUTHENTICATE_USER_TICKET,
+    GET_APP_BETAS,
+    GET_LEADERBOARDS_FOR_GAME,
+    GET_LEADERBOARD_ENTRIES,
+    GET_SERVER_LIST,
+    GET_SERVERS_AT_ADDRESS,
+    GET_SUPPORTED_API_LIST,
+    GET_PARTNER_SUPPORTED_API_LIST,
+    GET_SERVER_INFO,
+    GET_WISHLIST,
+    GET_WISHLIST_ITEM_COUNT,
+    GET_USER_GROUP_LIST,
+    IS_PLAYING_SHARED_GAME,
+    GET_FAMILY_GROUP_FOR_USER,
+    GET_FAMILY_GROUP,
+    GET_SHARED_LIBRARY_APPS,
+    GET_PROFILE_ITEMS_EQUIPPED,
+    GET_ANIMATED_AVATAR,
+    GET_AVATAR_FRAME,
+    GET_MINI_PROFILE_BACKGROUND,
+    GET_PROFILE_BACKGROUND,
+];
+
 impl<'a> SteamEndpoint<'a> {
-    pub fn url(self, key: &str) -> String {
-        let resource = match &self {
-            Self::GetOwnedGames { .. } => "/IPlayerService/GetOwnedGames/v0001/",
-            Self::GetPlayerAchievements { .. } => "/ISteamUserStats/GetPlayerAchievements/v0001/",
-            Self::ResolveVanityUrl { .. } => "/ISteamUser/ResolveVanityURL/v1/",
-            Self::GetPlayerSummaries { .. } => "/ISteamUser/GetPlayerSummaries/v2/",
-            Self::GetGlobalAchievementPercentagesForApp { .. } => {
-                "/ISteamUserStats/GetGlobalAchievementPercentagesForApp/v2/"
+    fn resource(&self) -> Resource {
+        match self {
+            Self::GetOwnedGames { .. } => GET_OWNED_GAMES,
+            Self::GetPlayerAchievements { .. } => GET_PLAYER_ACHIEVEMENTS,
+            Self::ResolveVanityUrl { .. } => RESOLVE_VANITY_URL,
+            Self::GetPlayerSummaries { .. } => GET_PLAYER_SUMMARIES,
+            Self::GetGlobalAchievementPercentagesForApp { .. } => GET_GLOBAL_ACHIEVEMENT_PERCENTAGES_FOR_APP,
+            Self::GetSchemaForGame { .. } => GET_SCHEMA_FOR_GAME,
+            Self::GetRecentlyPlayedGames { .. } => GET_RECENTLY_PLAYED_GAMES,
+            Self::GetAssetPrices { .. } => GET_ASSET_PRICES,
+            Self::GetAssetClassInfo { .. } => GET_ASSET_CLASS_INFO,
+            Self::GetTradeOffers { .. } => GET_TRADE_OFFERS,
+            Self::GetTradeOffer { .. } => GET_TRADE_OFFER,
+            Self::GetTradeOffersSummary { .. } => GET_TRADE_OFFERS_SUMMARY,
+            Self::GetTradeHistory { .. } => GET_TRADE_HISTORY,
+            Self::GetTradeHoldDurations { .. } => GET_TRADE_HOLD_DURATIONS,
+            Self::CheckAppOwnership { .. } => CHECK_APP_OWNERSHIP,
+            Self::GetPublisherAppOwnership { .. } => GET_PUBLISHER_APP_OWNERSHIP,
+            Self::AuthenticateUserTicket { .. } => AUTHENTICATE_USER_TICKET,
+            Self::GetAppBetas { .. } => GET_APP_BETAS,
+            Self::GetLeaderboardsForGame { .. } => GET_LEADERBOARDS_FOR_GAME,
+            Self::GetLeaderboardEntries { .. } => GET_LEADERBOARD_ENTRIES,
+            Self::GetServerList { .. } => GET_SERVER_LIST,
+            Self::GetServersAtAddress { .. } => GET_SERVERS_AT_ADDRESS,
+            Self::GetSupportedApiList { host: Host::Partner } => GET_PARTNER_SUPPORTED_API_LIST,
+            Self::GetSupportedApiList { .. } => GET_SUPPORTED_API_LIST,
+            Self::GetServerInfo => GET_SERVER_INFO,
+            Self::GetWishlist { .. } => GET_WISHLIST,
+            Self::GetWishlistItemCount { .. } => GET_WISHLIST_ITEM_COUNT,
+            Self::GetUserGroupList { .. } => GET_USER_GROUP_LIST,
+            Self::IsPlayingSharedGame { .. } => IS_PLAYING_SHARED_GAME,
+            Self::GetFamilyGroupForUser { .. } => GET_FAMILY_GROUP_FOR_USER,
+            Self::GetFamilyGroup { .. } => GET_FAMILY_GROUP,
+            Self::GetSharedLibraryApps { .. } => GET_SHARED_LIBRARY_APPS,
+            Self::GetProfileItemsEquipped { .. } => GET_PROFILE_ITEMS_EQUIPPED,
+            Self::GetAnimatedAvatar { .. } => GET_ANIMATED_AVATAR,
+            Self::GetAvatarFrame { .. } => GET_AVATAR_FRAME,
+            Self::GetMiniProfileBackground { .. } => GET_MINI_PROFILE_BACKGROUND,
+            Self::GetProfileBackground { .. } => GET_PROFILE_BACKGROUND,
+        }
+    }
+
+    /// Returns credentials needed by the endpoint.
+    pub fn credential(&self) -> Credential {
+        match self {
+            Self::GetGlobalAchievementPercentagesForApp { .. }
+            | Self::GetServersAtAddress { .. }
+            | Self::GetServerInfo => Credential::None,
+            Self::GetSupportedApiList { host: Host::Partner } => Credential::PublisherKey,
+            Self::GetSupportedApiList { .. }
+            | Self::GetWishlist { .. }
+            | Self::GetWishlistItemCount { .. }
+            | Self::GetProfileItemsEquipped { .. }
+            | Self::GetAnimatedAvatar { .. }
+            | Self::GetAvatarFrame { .. }
+            | Self::GetMiniProfileBackground { .. }
+            | Self::GetProfileBackground { .. } => Credential::Optional,
+            Self::GetPlayerAchievements { .. }
+            | Self::ResolveVanityUrl { .. }
+            | Self::GetPlayerSummaries { .. }
+            | Self::GetSchemaForGame { .. }
+            | Self::GetAssetPrices { .. }
+            | Self::GetAssetClassInfo { .. }
+            | Self::GetServerList { .. }
+            | Self::GetUserGroupList { .. } => Credential::ApiKey,
+            Self::GetOwnedGames { .. }
+            | Self::GetRecentlyPlayedGames { .. }
+            | Self::GetTradeOffers { .. }
+            | Self::GetTradeOffer { .. }
+            | Self::GetTradeOffersSummary { .. }
+            | Self::GetTradeHistory { .. }
+            | Self::GetTradeHoldDurations { .. }
+            | Self::IsPlayingSharedGame { .. } => Credential::Any,
+            Self::GetFamilyGroupForUser { .. } | Self::GetFamilyGroup { .. } | Self::GetSharedLibraryApps { .. } => {
+                Credential::AccessToken
             }
-            Self::GetSchemaForGame { .. } => "/ISteamUserStats/GetSchemaForGame/v0002/",
-            Self::GetRecentlyPlayedGames { .. } => "/IPlayerService/GetRecentlyPlayedGames/v1/"
-        };
+            Self::CheckAppOwnership { .. }
+            | Self::GetPublisherAppOwnership { .. }
+            | Self::AuthenticateUserTicket { .. }
+            | Self::GetAppBetas { .. }
+            | Self::GetLeaderboardsForGame { .. }
+            | Self::GetLeaderboardEntries { .. } => Credential::PublisherKey,
+        }
+    }
+
+    fn query(self) -> Query {
+        let query = Query::default();
 
         match self {
             Self::GetOwnedGames {
                 id,
                 include_appinfo,
                 include_free_games,
-            } => format!(
-                "{}{}?key={}&steamid={}&include_appinfo={}&include_played_free_games={}",
-                BASE_URL, resource, key, id, include_appinfo, include_free_games
-            ),
-            Self::GetPlayerAchievements { id, appid, lang } => match lang {
-                Some(l) => format!(
-                    "{}{}?key={}&steamid={}&appid={}&l={}",
-                    BASE_URL, resource, key, id, appid, l
-                ),
-                None => format!("{}{}?key={}&steamid={}&appid={}", BASE_URL, resource, key, id, appid),
-            },
-            Self::ResolveVanityUrl { url } => {
-                format!("{}{}?key={}&vanityurl={}", BASE_URL, resource, key, url)
+            } => query
+                .with("steamid", id)
+                .with("include_appinfo", include_appinfo)
+                .with("include_played_free_games", include_free_games),
+            Self::GetPlayerAchievements { id, appid, lang } => {
+                query.with("steamid", id).with("appid", appid).with_opt("l", lang)
+            }
+            Self::ResolveVanityUrl { url } => query.with("vanityurl", url),
+            Self::GetPlayerSummaries { steam_id } => query.with("steamids", steam_id),
+            Self::GetGlobalAchievementPercentagesForApp { appid } => query.with("gameid", appid),
+            Self::GetSchemaForGame { appid } => query.with("appid", appid),
+            Self::GetRecentlyPlayedGames { steam_id } => query.with("steamid", steam_id),
+            Self::GetAssetPrices { appid, currency, lang } => query
+                .with("appid", appid)
+                .with_opt("currency", currency)
+                .with_opt("language", lang),
+            Self::GetAssetClassInfo { appid, lang, classes } => {
+                let mut query = query
+                    .with("appid", appid)
+                    .with("class_count", classes.len())
+                    .with_opt("language", lang);
+                // classes are passed as indexed parameters, `classid0`, `instanceid0`, `classid1`...
+                for (i, class) in classes.iter().enumerate() {
+                    query = query
+                        .with(format!("classid{}", i), class.classid)
+                        .with_opt(format!("instanceid{}", i), class.instanceid);
+                }
+                query
+            }
+            Self::GetTradeOffers { query: q } => query
+                .with("get_sent_offers", q.get_sent_offers)
+                .with("get_received_offers", q.get_received_offers)
+                .with("get_descriptions", q.get_descriptions)
+                .with("active_only", q.active_only)
+                .with("historical_only", q.historical_only)
+                .with_opt("language", q.language.as_ref())
+                .with_opt("time_historical_cutoff", q.time_historical_cutoff)
+                .with_opt("cursor", q.cursor),
+            Self::GetTradeOffer { tradeofferid, lang } => {
+                let query = query.with("tradeofferid", tradeofferid);
+                match lang {
+                    Some(l) => query.with("get_descriptions", true).with("language", l),
+                    None => query,
+                }
             }
-            Self::GetPlayerSummaries { steam_id } => {
-                format!("{}{}?key={}&steamids={}", BASE_URL, resource, key, steam_id)
+            Self::GetTradeOffersSummary { time_last_visit } => {
+                query.with("time_last_visit", time_last_visit.unwrap_or(0))
             }
-            Self::GetGlobalAchievementPercentagesForApp { appid } => {
-                format!("{}{}?key={}&gameid={}", BASE_URL, resource, key, appid)
+            Self::GetTradeHistory { query: q } => query
+                .with("max_trades", q.max_trades)
+                .with("navigating_back", q.navigating_back)
+                .with("get_descriptions", q.get_descriptions)
+                .with("include_failed", q.include_failed)
+                .with("include_total", q.include_total)
+                .with_opt("language", q.language.as_ref())
+                .with_opt("start_after_time", q.start_after_time)
+                .with_opt("start_after_tradeid", q.start_after_tradeid.as_ref()),
+            Self::GetTradeHoldDurations {
+                steam_id,
+                trade_offer_access_token,
+            } => query
+                .with("steamid_target", steam_id)
+                .with_opt("trade_offer_access_token", trade_offer_access_token),
+            Self::CheckAppOwnership { steam_id, appid } => query.with("steamid", steam_id).with("appid", appid),
+            Self::GetPublisherAppOwnership { steam_id } => query.with("steamid", steam_id),
+            Self::AuthenticateUserTicket { appid, ticket, identity } => {
+                // ticket is sent as hex string
+                let ticket: String = ticket.iter().map(|b| format!("{:02X}", b)).collect();
+                query
+                    .with("appid", appid)
+                    .with("ticket", ticket)
+                    .with_opt("identity", identity)
             }
-            Self::GetSchemaForGame { appid } => {
-                format!("{}{}?key={}&appid={}", BASE_URL, resource, key, appid)
+            Self::GetAppBetas { appid } => query.with("appid", appid),
+            Self::GetLeaderboardsForGame { appid } => query.with("appid", appid),
+            Self::GetLeaderboardEntries {
+                appid,
+                leaderboard_id,
+                range,
+            } => {
+                let query = query.with("appid", appid).with("leaderboardid", leaderboard_id);
+                // `datarequest` values are from `ELeaderboardDataRequest`
+                match range {
+                    LeaderboardRange::Global { start, end } => query
+                        .with("datarequest", 0)
+                        .with("rangestart", start)
+                        .with("rangeend", end),
+                    LeaderboardRange::AroundUser { steam_id, before, after } => query
+                        .with("datarequest", 1)
+                        .with("steamid", steam_id)
+                        .with("rangestart", -i64::from(before))
+                        .with("rangeend", after),
+                    LeaderboardRange::Friends { steam_id } => query.with("datarequest", 2).with("steamid", steam_id),
+                }
             }
-            Self::GetRecentlyPlayedGames {steam_id} => {
-                format!("{}{}?key={}&steamid={}", BASE_URL, resource, key, steam_id)
+            Self::GetServerList { filter, limit } => query.with("filter", filter).with_opt("limit", limit),
+            Self::GetServersAtAddress { addr } => query.with("addr", addr),
+            Self::GetSupportedApiList { .. } | Self::GetServerInfo => query,
+            Self::GetWishlist { steam_id } => query.with("steamid", steam_id),
+            Self::GetWishlistItemCount { steam_id } => query.with("steamid", steam_id),
+            Self::GetUserGroupList { steam_id } => query.with("steamid", steam_id),
+            Self::IsPlayingSharedGame { steam_id, appid } => {
+                query.with("steamid", steam_id).with("appid_playing", appid)
+            }
+            Self::GetFamilyGroupForUser { steam_id } => query
+                .with("steamid", steam_id)
+                .with("include_family_group_response", true),
+            Self::GetFamilyGroup { family_group_id } => query.with("family_groupid", family_group_id),
+            Self::GetSharedLibraryApps {
+                family_group_id,
+                include_own,
+                lang,
+            } => query
+                .with("family_groupid", family_group_id)
+                .with("include_own", include_own)
+                .with_opt("language", lang),
+            Self::GetProfileItemsEquipped { steam_id, lang }
+            | Self::GetAnimatedAvatar { steam_id, lang }
+            | Self::GetAvatarFrame { steam_id, lang }
+            | Self::GetMiniProfileBackground { steam_id, lang }
+            | Self::GetProfileBackground { steam_id, lang } => {
+                query.with("steamid", steam_id).with_opt("language", lang)
             }
         }
     }
+
+    /// Builds url of the endpoint on the client's host, with credentials required by the endpoint.
+    ///
+    /// Credentials have to be in the query, GET methods of Web API read `key` and `access_token` only from
+    /// query parameters and have no documented header for them. The url is therefore never logged, and it is
+    /// redacted in errors and diagnostics.
+    pub fn url(self, client: &SteamClient) -> Result<String, SasError> {
+        let resource = self.resource();
+        let endpoint = resource.path();
+
+        let credential = self.credential().param(client, &endpoint)?;
+        let mut url = base_url(client, resource.host, &endpoint)?;
+        {
+            let mut pairs = url.query_pairs_mut();
+            if let Some((name, value)) = credential {
+                pairs.append_pair(name, value);
+            }
+            for (name, value) in self.query().0 {
+                pairs.append_pair(&name, &value);
+            }
+        }
+
+        Ok(url.into())
+    }
 }
 
-pub enum SteamImageEndpoint {
-    SmallCapsule {
-        appid: AppId
-    },
-    LibraryCapsule {
-        appid: AppId
+impl Credential {
+    /// Returns query parameter with the credential from the client, if the endpoint needs any.
+    pub fn param<'c>(&self, client: &'c SteamClient, endpoint: &str) -> Result<Option<(&'static str, &'c str)>, SasError> {
+        let auth = &client.auth;
+
+        let missing = |required| SasError::MissingCredential {
+            endpoint: endpoint.to_owned(),
+            required,
+        };
+
+        match (self, auth.api_key(), auth.access_token()) {
+            (Credential::None, ..) => Ok(None),
+            (Credential::PublisherKey, ..) => match &client.publisher_key {
+                Some(key) => Ok(Some(("key", key.as_str()))),
+                None => Err(missing("publisher key")),
+            },
+            (Credential::ApiKey | Credential::Optional | Credential::Any, Some(key), _) => Ok(Some(("key", key))),
+            (Credential::Optional | Credential::Any | Credential::AccessToken, _, Some(token)) => {
+                Ok(Some(("access_token", token)))
+            }
+            (Credential::Optional, None, None) => Ok(None),
+            (Credential::ApiKey, None, _) => Err(missing("api key")),
+            (Credential::Any, None, None) => Err(missing("api key or access token")),
+            (Credential::AccessToken, _, None) => Err(missing("access token")),
+        }
     }
 }
 
-impl SteamImageEndpoint {
+/// Returns url of given path on the client's host.
+pub fn base_url(client: &SteamClient, host: Host, path: &str) -> Result<Url, SasError> {
+    let base = client.hosts.get(host);
+    Url::parse(&format!("{}{}", base, path))
+        .map_err(|_| SasError::InvalidUrl { url: base.to_owned() })
+}
+
+/// List of query parameters.
+#[derive(Default)]
+struct Query(Vec<(Cow<'static, str>, String)>);
+
+impl Query {
+    fn with(mut self, name: impl Into<Cow<'static, str>>, value: impl Display) -> Self {
+        self.0.push((name.into(), value.to_string()));
+        self
+    }
+
+    fn with_opt(self, name: impl Into<Cow<'static, str>>, value: Option<impl Display>) -> Self {
+        match value {
+            Some(value) => self.with(name, value),
+            None => self,
+        }
+    }
+}
+
+pub enum SteamImageEndpoint<'a> {
+    App {
+        appid: AppId,
+        image: AppImage<'a>,
+    },
+}
+
+impl<'a> SteamImageEndpoint<'a> {
     pub fn url(self) -> String {
         match self {
-            SteamImageEndpoint::SmallCapsule { appid } => format!("{}/{}/capsule_231x87.jpg", BASE_IMAGE_URL, appid),
-            SteamImageEndpoint::LibraryCapsule { appid } => format!("{}/{}/library_600x900.jpg", BASE_IMAGE_URL, appid),
+            SteamImageEndpoint::App { appid, image } => {
+                let file = match image {
+                    AppImage::Icon(hash) => return format!("{}/{}/{}.jpg", BASE_ICON_URL, appid, hash),
+                    AppImage::SmallCapsule => "capsule_231x87.jpg",
+                    AppImage::MainCapsule => "capsule_616x353.jpg",
+                    AppImage::ListCapsule => "capsule_sm_120.jpg",
+                    AppImage::Header => "header.jpg",
+                    AppImage::LibraryCapsule => "library_600x900.jpg",
+                    AppImage::LibraryCapsule2x => "library_600x900_2x.jpg",
+                    AppImage::LibraryHero => "library_hero.jpg",
+                    AppImage::Logo => "logo.png",
+                    AppImage::PageBackground => "page_bg_generated_v6b.jpg",
+                };
+                format!("{}/{}/{}", BASE_IMAGE_URL, appid, file)
+            }
+        }
+    }
+}
+
+pub enum SteamStoreEndpoint<'a> {
+    AppDetails {
+        appid: AppId,
+        cc: Option<&'a str>,
+        lang: Option<&'a str>,
+    },
+}
+
+impl<'a> SteamStoreEndpoint<'a> {
+    pub fn url(self, client: &SteamClient) -> Result<String, SasError> {
+        match self {
+            SteamStoreEndpoint::AppDetails { appid, cc, lang } => {
+                let mut url = base_url(client, Host::Store, "/api/appdetails")?;
+                let query = Query::default().with("appids", appid).with_opt("cc", cc).with_opt("l", lang);
+                url.query_pairs_mut().extend_pairs(query.0);
+                Ok(url.into())
+            }
+        }
+    }
+}
+
+pub enum SteamCommunityEndpoint {
+    GroupMembers {
+        group_id: SteamId,
+        page: u32,
+    },
+}
+
+impl SteamCommunityEndpoint {
+    pub fn url(self, client: &SteamClient) -> Result<String, SasError> {
+        match self {
+            SteamCommunityEndpoint::GroupMembers { group_id, page } => {
+                let mut url = base_url(client, Host::Community, &format!("/gid/{}/memberslistxml/", group_id))?;
+                url.query_pairs_mut()
+                    .append_pair("xml", "1")
+                    .append_pair("p", &page.to_string());
+                Ok(url.into())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Endpoint of every variant.
+    ///
+    /// The match in [covered] stops compiling when a variant is added, add its sample here as well.
+    fn samples<'a>(
+        trade_offers: &'a TradeOffersQuery,
+        trade_history: &'a TradeHistoryQuery,
+        filter: &'a ServerFilter,
+    ) -> Vec<SteamEndpoint<'a>> {
+        let id = SteamId(76561197960287930);
+        let appid = AppId(400);
+
+        vec![
+            SteamEndpoint::GetOwnedGames {
+                id: "id",
+                include_appinfo: true,
+                include_free_games: true,
+            },
+            SteamEndpoint::GetPlayerAchievements { id: "id", appid, lang: None },
+            SteamEndpoint::ResolveVanityUrl { url: "vanity" },
+            SteamEndpoint::GetPlayerSummaries { steam_id: "id" },
+            SteamEndpoint::GetGlobalAchievementPercentagesForApp { appid },
+            SteamEndpoint::GetSchemaForGame { appid },
+            SteamEndpoint::GetRecentlyPlayedGames { steam_id: "id" },
+            SteamEndpoint::GetAssetPrices {
+                appid,
+                currency: None,
+                lang: None,
+            },
+            SteamEndpoint::GetAssetClassInfo {
+                appid,
+                lang: None,
+                classes: &[],
+            },
+            SteamEndpoint::GetTradeOffers { query: trade_offers },
+            SteamEndpoint::GetTradeOffer {
+                tradeofferid: "1",
+                lang: None,
+            },
+            SteamEndpoint::GetTradeOffersSummary { time_last_visit: None },
+            SteamEndpoint::GetTradeHistory { query: trade_history },
+            SteamEndpoint::GetTradeHoldDurations {
+                steam_id: "id",
+                trade_offer_access_token: None,
+            },
+            SteamEndpoint::CheckAppOwnership { steam_id: "id", appid },
+            SteamEndpoint::GetPublisherAppOwnership { steam_id: "id" },
+            SteamEndpoint::AuthenticateUserTicket {
+                appid,
+                ticket: &[],
+                identity: None,
+            },
+            SteamEndpoint::GetAppBetas { appid },
+            SteamEndpoint::GetLeaderboardsForGame { appid },
+            SteamEndpoint::GetLeaderboardEntries {
+                appid,
+                leaderboard_id: 1,
+                range: LeaderboardRange::Global { start: 1, end: 10 },
+            },
+            SteamEndpoint::GetServerList { filter, limit: None },
+            SteamEndpoint::GetServersAtAddress { addr: "127.0.0.1" },
+            SteamEndpoint::GetSupportedApiList { host: Host::Api },
+            SteamEndpoint::GetSupportedApiList { host: Host::Partner },
+            SteamEndpoint::GetServerInfo,
+            SteamEndpoint::GetWishlist { steam_id: "id" },
+            SteamEndpoint::GetWishlistItemCount { steam_id: "id" },
+            SteamEndpoint::GetUserGroupList { steam_id: "id" },
+            SteamEndpoint::IsPlayingSharedGame { steam_id: id, appid },
+            SteamEndpoint::GetFamilyGroupForUser { steam_id: id },
+            SteamEndpoint::GetFamilyGroup { family_group_id: "1" },
+            SteamEndpoint::GetSharedLibraryApps {
+                family_group_id: "1",
+                include_own: true,
+                lang: None,
+            },
+            SteamEndpoint::GetProfileItemsEquipped { steam_id: id, lang: None },
+            SteamEndpoint::GetAnimatedAvatar { steam_id: id, lang: None },
+            SteamEndpoint::GetAvatarFrame { steam_id: id, lang: None },
+            SteamEndpoint::GetMiniProfileBackground { steam_id: id, lang: None },
+            SteamEndpoint::GetProfileBackground { steam_id: id, lang: None },
+        ]
+    }
+
+    /// Exhaustive match, fails to compile for variants missing in [samples].
+    fn covered(endpoint: &SteamEndpoint) {
+        match endpoint {
+            SteamEndpoint::GetOwnedGames { .. }
+            | SteamEndpoint::GetPlayerAchievements { .. }
+            | SteamEndpoint::ResolveVanityUrl { .. }
+            | SteamEndpoint::GetPlayerSummaries { .. }
+            | SteamEndpoint::GetGlobalAchievementPercentagesForApp { .. }
+            | SteamEndpoint::GetSchemaForGame { .. }
+            | SteamEndpoint::GetRecentlyPlayedGames { .. }
+            | SteamEndpoint::GetAssetPrices { .. }
+            | SteamEndpoint::GetAssetClassInfo { .. }
+            | SteamEndpoint::GetTradeOffers { .. }
+            | SteamEndpoint::GetTradeOffer { .. }
+            | SteamEndpoint::GetTradeOffersSummary { .. }
+            | SteamEndpoint::GetTradeHistory { .. }
+            | SteamEndpoint::GetTradeHoldDurations { .. }
+            | SteamEndpoint::CheckAppOwnership { .. }
+            | SteamEndpoint::GetPublisherAppOwnership { .. }
+            | SteamEndpoint::AuthenticateUserTicket { .. }
+            | SteamEndpoint::GetAppBetas { .. }
+            | SteamEndpoint::GetLeaderboardsForGame { .. }
+            | SteamEndpoint::GetLeaderboardEntries { .. }
+            | SteamEndpoint::GetServerList { .. }
+            | SteamEndpoint::GetServersAtAddress { .. }
+            | SteamEndpoint::GetSupportedApiList { .. }
+            | SteamEndpoint::GetServerInfo
+            | SteamEndpoint::GetWishlist { .. }
+            | SteamEndpoint::GetWishlistItemCount { .. }
+            | SteamEndpoint::GetUserGroupList { .. }
+            | SteamEndpoint::IsPlayingSharedGame { .. }
+            | SteamEndpoint::GetFamilyGroupForUser { .. }
+            | SteamEndpoint::GetFamilyGroup { .. }
+            | SteamEndpoint::GetSharedLibraryApps { .. }
+            | SteamEndpoint::GetProfileItemsEquipped { .. }
+            | SteamEndpoint::GetAnimatedAvatar { .. }
+            | SteamEndpoint::GetAvatarFrame { .. }
+            | SteamEndpoint::GetMiniProfileBackground { .. }
+            | SteamEndpoint::GetProfileBackground { .. } => {}
+        }
+    }
+
+    #[test]
+    fn resources_match_endpoints() {
+        let (trade_offers, trade_history, filter) = Default::default();
+        let samples = samples(&trade_offers, &trade_history, &filter);
+        samples.iter().for_each(covered);
+
+        let used: Vec<Resource> = samples.iter().map(|endpoint| endpoint.resource()).collect();
+
+        for resource in &used {
+            assert!(RESOURCES.contains(resource), "{} is missing in RESOURCES", resource.path());
+        }
+        for resource in RESOURCES {
+            assert!(used.contains(resource), "{} is not used by any endpoint", resource.path());
         }
+        assert_eq!(used.len(), RESOURCES.len(), "RESOURCES has duplicates");
     }
 }
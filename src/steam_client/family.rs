@@ -0,0 +1,90 @@
+use crate::{
+    entity::{FamilyGroup, FamilyGroupForUser, SharedApp},
+    error::SasError,
+    SteamId,
+};
+
+use super::{endpoint::SteamEndpoint, response::SharedApps, SteamClient};
+
+impl SteamClient {
+    /// Returns family group membership of given player, with details of the family group.
+    ///
+    /// Needs access token.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use steam_api_client::{SteamClient, SteamId};
+    ///
+    /// # tokio_test::block_on(async {
+    /// let client = SteamClient::new();
+    /// match client.get_family_group_for_user(SteamId(76561197960287930)).await {
+    ///     Ok(membership) => {/*Family group membership*/},
+    ///     Err(e) => {/*Error*/}
+    /// }
+    /// # });
+    /// ```
+    #[tracing::instrument(skip(self))]
+    pub async fn get_family_group_for_user(&self, steam_id: SteamId) -> Result<FamilyGroupForUser, SasError> {
+        let url = SteamEndpoint::GetFamilyGroupForUser { steam_id }.url(self)?;
+
+        self.get_response(url).await
+    }
+
+    /// Returns family group with given ID.
+    ///
+    /// Needs access token.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use steam_api_client::SteamClient;
+    ///
+    /// # tokio_test::block_on(async {
+    /// let client = SteamClient::new();
+    /// match client.get_family_group("familyGroupId").await {
+    ///     Ok(group) => {/*Family group*/},
+    ///     Err(e) => {/*Error*/}
+    /// }
+    /// # });
+    /// ```
+    #[tracing::instrument(skip(self))]
+    pub async fn get_family_group(&self, family_group_id: &str) -> Result<FamilyGroup, SasError> {
+        let url = SteamEndpoint::GetFamilyGroup { family_group_id }.url(self)?;
+
+        self.get_response(url).await
+    }
+
+    /// Returns apps shared in given family group, with their owners.
+    ///
+    /// Apps owned by the token owner are included with `include_own`. Needs access token.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use steam_api_client::SteamClient;
+    ///
+    /// # tokio_test::block_on(async {
+    /// let client = SteamClient::new();
+    /// match client.get_shared_library_apps("familyGroupId", true, Some("english")).await {
+    ///     Ok(apps) => {/*List of shared apps*/},
+    ///     Err(e) => {/*Error*/}
+    /// }
+    /// # });
+    /// ```
+    #[tracing::instrument(skip(self))]
+    pub async fn get_shared_library_apps(
+        &self,
+        family_group_id: &str,
+        include_own: bool,
+        lang: Option<&str>,
+    ) -> Result<Vec<SharedApp>, SasError> {
+        let url = SteamEndpoint::GetSharedLibraryApps {
+            family_group_id,
+            include_own,
+            lang,
+        }
+        .url(self)?;
+
+        let res: SharedApps = self.get_response(url).await?;
+
+        Ok(res.apps.unwrap_or_default())
+    }
+}
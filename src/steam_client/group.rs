@@ -0,0 +1,100 @@
+use futures::{stream, Stream, TryStreamExt};
+
+use crate::{
+    entity::{GroupMembers, UserGroup, Visibility},
+    error::SasError,
+    SteamId,
+};
+
+use super::{
+    endpoint::{SteamCommunityEndpoint, SteamEndpoint},
+    response::{Envelope, UserGroups},
+    SteamClient,
+};
+
+impl SteamClient {
+    /// Returns list of groups the player is member of.
+    ///
+    /// Groups of private profiles are [Visibility::Private].
+    ///
+    /// # Examples
+    /// ```rust
+    /// use steam_api_client::{entity::Visibility, SteamClient};
+    ///
+    /// # tokio_test::block_on(async {
+    /// let client = SteamClient::new();
+    /// match client.get_user_group_list("playerId").await {
+    ///     Ok(Visibility::Public(groups)) => {/*List of groups*/},
+    ///     Ok(Visibility::Private) => {/*Profile is private*/},
+    ///     Err(e) => {/*Error*/}
+    /// }
+    /// # });
+    /// ```
+    #[tracing::instrument(skip(self))]
+    pub async fn get_user_group_list(&self, id: &str) -> Result<Visibility<Vec<UserGroup>>, SasError> {
+        let url = SteamEndpoint::GetUserGroupList { steam_id: id }.url(self)?;
+
+        self.get_checked(url, |res: Envelope<UserGroups>| res.response.visibility())
+            .await
+    }
+
+    /// Returns page of members of given group, pages start from 1.
+    ///
+    /// Uses community XML feed, each page has up to 1000 members.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use steam_api_client::{SteamClient, SteamId};
+    ///
+    /// # tokio_test::block_on(async {
+    /// let client = SteamClient::new();
+    /// match client.get_group_members(SteamId(103582791429521412), 1).await {
+    ///     Ok(page) => {/*Page of members*/},
+    ///     Err(e) => {/*Error*/}
+    /// }
+    /// # });
+    /// ```
+    #[tracing::instrument(skip(self))]
+    pub async fn get_group_members(&self, group_id: SteamId, page: u32) -> Result<GroupMembers, SasError> {
+        let url = SteamCommunityEndpoint::GroupMembers { group_id, page }.url(self)?;
+
+        self.get_xml(url).await
+    }
+
+    /// Returns stream of all members of given group, requesting pages as needed.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use futures::TryStreamExt;
+    /// use steam_api_client::{SteamClient, SteamId};
+    ///
+    /// # tokio_test::block_on(async {
+    /// let client = SteamClient::new();
+    /// let members = client.group_members_stream(SteamId(103582791429521412));
+    /// match members.try_collect::<Vec<_>>().await {
+    ///     Ok(members) => {/*All members*/},
+    ///     Err(e) => {/*Error*/}
+    /// }
+    /// # });
+    /// ```
+    pub fn group_members_stream(&self, group_id: SteamId) -> impl Stream<Item = Result<SteamId, SasError>> + '_ {
+        stream::try_unfold(Some(1), move |page: Option<u32>| async move {
+            let page = match page {
+                Some(page) => page,
+                None => return Ok(None),
+            };
+
+            let members = self.get_group_members(group_id, page).await?;
+
+            let next = if members.members.is_empty() || page >= members.total_pages {
+                None
+            } else {
+                Some(page + 1)
+            };
+
+            Ok::<_, SasError>(Some((members.members, next)))
+        })
+        .map_ok(|members| stream::iter(members.into_iter().map(Ok)))
+        .try_flatten()
+    }
+}
@@ -0,0 +1,66 @@
+/// Steam hosts used by the client.
+///
+/// Every host can be replaced using [SteamClient::set_host](crate::SteamClient::set_host), e.g. for testing.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Host {
+    /// Public Web API, `https://api.steampowered.com`
+    Api,
+    /// Publisher Web API, `https://partner.steam-api.com`
+    Partner,
+    /// Steam Community, `https://steamcommunity.com`
+    Community,
+    /// Steam Store, `https://store.steampowered.com`
+    Store,
+}
+
+impl Host {
+    /// Returns default base url of the host.
+    pub fn default_url(&self) -> &'static str {
+        match self {
+            Host::Api => "https://api.steampowered.com",
+            Host::Partner => "https://partner.steam-api.com",
+            Host::Community => "https://steamcommunity.com",
+            Host::Store => "https://store.steampowered.com",
+        }
+    }
+}
+
+/// Base urls of all hosts.
+pub(crate) struct Hosts {
+    api: String,
+    partner: String,
+    community: String,
+    store: String,
+}
+
+impl Hosts {
+    pub fn get(&self, host: Host) -> &str {
+        match host {
+            Host::Api => &self.api,
+            Host::Partner => &self.partner,
+            Host::Community => &self.community,
+            Host::Store => &self.store,
+        }
+    }
+
+    pub fn set(&mut self, host: Host, base_url: String) {
+        let base_url = base_url.trim_end_matches('/').to_owned();
+        match host {
+            Host::Api => self.api = base_url,
+            Host::Partner => self.partner = base_url,
+            Host::Community => self.community = base_url,
+            Host::Store => self.store = base_url,
+        }
+    }
+}
+
+impl Default for Hosts {
+    fn default() -> Self {
+        Self {
+            api: Host::Api.default_url().to_owned(),
+            partner: Host::Partner.default_url().to_owned(),
+            community: Host::Community.default_url().to_owned(),
+            store: Host::Store.default_url().to_owned(),
+        }
+    }
+}
@@ -0,0 +1,349 @@
+use std::path::PathBuf;
+
+use bytes::Bytes;
+use futures::{stream, Stream};
+use reqwest::{header::CONTENT_TYPE, Response, StatusCode};
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+use tracing::{error, info_span};
+use tracing_futures::Instrument;
+
+use crate::{
+    entity::{AppImage, DownloadProgress, Image, ImageFormat, ImageSource},
+    error::SasError,
+    AppId,
+};
+
+use super::{check_status, diagnostics::Capture, endpoint::SteamImageEndpoint, SteamClient};
+
+impl SteamClient {
+    /// Returns given image of given app with its format, `None` when the app does not have the image.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use steam_api_client::{AppId, SteamClient};
+    /// use steam_api_client::entity::AppImage;
+    ///
+    /// # tokio_test::block_on(async {
+    /// let client = SteamClient::new();
+    /// match client.get_app_image(AppId(400), AppImage::Header).await {
+    ///     Ok(Some(image)) => {/*Image*/},
+    ///     Ok(None) => {/*Missing image*/},
+    ///     Err(e) => {/*Error*/}
+    /// }
+    /// # });
+    /// ```
+    #[tracing::instrument(skip(self))]
+    pub async fn get_app_image(&self, appid: AppId, image: AppImage<'_>) -> Result<Option<Image>, SasError> {
+        let url = SteamImageEndpoint::App { appid, image }.url();
+
+        self.get_optional_image(&url).await
+    }
+
+    /// Downloads given image with its content type, `None` when the image does not exist.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use steam_api_client::SteamClient;
+    /// use steam_api_client::entity::{AvatarSize, ImageSource};
+    ///
+    /// # tokio_test::block_on(async {
+    /// let client = SteamClient::new();
+    /// if let Ok(Some(player)) = client.get_player_info("playerId").await {
+    ///     let source = ImageSource::Avatar { player: &player, size: AvatarSize::Medium };
+    ///     match client.download_image(source).await {
+    ///         Ok(Some(image)) => {/*Image with content type*/},
+    ///         Ok(None) => {/*Missing image*/},
+    ///         Err(e) => {/*Error*/}
+    ///     }
+    /// }
+    /// # });
+    /// ```
+    #[tracing::instrument(skip(self))]
+    pub async fn download_image(&self, source: ImageSource<'_>) -> Result<Option<Image>, SasError> {
+        let url = match image_url(source) {
+            Some(url) => url,
+            None => return Ok(None),
+        };
+
+        self.get_optional_image(&url).await
+    }
+
+    /// Returns path of given image in image cache, downloading it when needed.
+    ///
+    /// Returns `None` when the image does not exist. Needs image cache set with [SteamClient::set_image_cache].
+    ///
+    /// # Examples
+    /// ```rust
+    /// use steam_api_client::{AppId, SteamClient};
+    /// use steam_api_client::entity::{AppImage, ImageSource};
+    ///
+    /// # tokio_test::block_on(async {
+    /// let mut client = SteamClient::new();
+    /// client.set_image_cache(std::env::temp_dir().join("steam-images"), 64 * 1024 * 1024);
+    /// let source = ImageSource::App { appid: AppId(400), image: AppImage::LibraryHero };
+    /// match client.get_image_path(source).await {
+    ///     Ok(Some(path)) => {/*Path of cached image*/},
+    ///     Ok(None) => {/*Missing image*/},
+    ///     Err(e) => {/*Error*/}
+    /// }
+    /// # });
+    /// ```
+    #[tracing::instrument(skip(self))]
+    pub async fn get_image_path(&self, source: ImageSource<'_>) -> Result<Option<PathBuf>, SasError> {
+        let cache = self.image_cache.as_ref().ok_or(SasError::NoImageCache)?;
+
+        let url = match image_url(source) {
+            Some(url) => url,
+            None => return Ok(None),
+        };
+
+        cache.get_path(&self.client, self.diagnostics.as_ref(), &url).await
+    }
+
+    /// Returns given image as stream of chunks, `None` when the image does not exist.
+    ///
+    /// The stream fails with [SasError::ImageTooLarge] once it exceeds `max_size` bytes.
+    /// Streamed images are not stored in image cache.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use futures::TryStreamExt;
+    /// use steam_api_client::{AppId, SteamClient};
+    /// use steam_api_client::entity::{AppImage, ImageSource};
+    ///
+    /// # tokio_test::block_on(async {
+    /// let client = SteamClient::new();
+    /// let source = ImageSource::App { appid: AppId(400), image: AppImage::LibraryHero };
+    /// match client.stream_image(source, Some(16 * 1024 * 1024)).await {
+    ///     Ok(Some(chunks)) => {
+    ///         let chunks = chunks.try_collect::<Vec<_>>().await;
+    ///         /*Image chunks*/
+    ///     },
+    ///     Ok(None) => {/*Missing image*/},
+    ///     Err(e) => {/*Error*/}
+    /// }
+    /// # });
+    /// ```
+    #[tracing::instrument(skip(self))]
+    pub async fn stream_image(
+        &self,
+        source: ImageSource<'_>,
+        max_size: Option<u64>,
+    ) -> Result<Option<impl Stream<Item = Result<Bytes, SasError>>>, SasError> {
+        let res = match self.open_image(source, max_size).await? {
+            Some(res) => res,
+            None => return Ok(None),
+        };
+
+        let chunks = stream::try_unfold((res, 0), move |(mut res, downloaded): (Response, u64)| async move {
+            let chunk = match res.chunk().await? {
+                Some(chunk) => chunk,
+                None => return Ok(None),
+            };
+
+            let downloaded = downloaded + chunk.len() as u64;
+            check_size(downloaded, max_size)?;
+
+            Ok::<_, SasError>(Some((chunk, (res, downloaded))))
+        });
+
+        Ok(Some(chunks))
+    }
+
+    /// Writes given image into `writer` as it is downloaded, returns number of written bytes
+    /// or `None` when the image does not exist.
+    ///
+    /// `progress` is called after each written chunk. Fails with [SasError::ImageTooLarge] once the image
+    /// exceeds `max_size` bytes, leaving partial image in `writer`. Images are not stored in image cache.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use steam_api_client::{AppId, SteamClient};
+    /// use steam_api_client::entity::{AppImage, ImageSource};
+    ///
+    /// # tokio_test::block_on(async {
+    /// let client = SteamClient::new();
+    /// let source = ImageSource::App { appid: AppId(400), image: AppImage::LibraryHero };
+    /// let mut file = Vec::new();
+    /// match client.download_image_to(source, &mut file, None, |progress| {/*Progress*/}).await {
+    ///     Ok(Some(size)) => {/*Downloaded image*/},
+    ///     Ok(None) => {/*Missing image*/},
+    ///     Err(e) => {/*Error*/}
+    /// }
+    /// # });
+    /// ```
+    #[tracing::instrument(skip(self, writer, progress))]
+    pub async fn download_image_to<W: AsyncWrite + Unpin>(
+        &self,
+        source: ImageSource<'_>,
+        writer: &mut W,
+        max_size: Option<u64>,
+        mut progress: impl FnMut(DownloadProgress),
+    ) -> Result<Option<u64>, SasError> {
+        let mut res = match self.open_image(source, max_size).await? {
+            Some(res) => res,
+            None => return Ok(None),
+        };
+
+        let total = res.content_length();
+        let mut downloaded = 0;
+        while let Some(chunk) = res.chunk().await? {
+            downloaded += chunk.len() as u64;
+            check_size(downloaded, max_size)?;
+
+            writer.write_all(&chunk).await?;
+            progress(DownloadProgress { downloaded, total });
+        }
+        writer.flush().await?;
+
+        Ok(Some(downloaded))
+    }
+
+    /// Sends GET request for given image and checks the response before its body is read.
+    async fn open_image(&self, source: ImageSource<'_>, max_size: Option<u64>) -> Result<Option<Response>, SasError> {
+        let url = match image_url(source) {
+            Some(url) => url,
+            None => return Ok(None),
+        };
+
+        let res = self.client.get(&url).send().instrument(info_span!("Image request")).await?;
+
+        let capture = self.capture(&res);
+        let res = match check_image_status(res, &capture).await? {
+            Some(res) => res,
+            None => return Ok(None),
+        };
+
+        // body is not available yet, so only reported content type is checked
+        if let Some(content_type) = content_type(&res) {
+            check_content_type(Some(&content_type), true)?;
+        }
+
+        if let Some(size) = res.content_length() {
+            check_size(size, max_size)?;
+        }
+
+        Ok(Some(res))
+    }
+}
+
+/// Returns url of given image, `None` for players without avatar of given size and achievements without icons.
+fn image_url(source: ImageSource<'_>) -> Option<String> {
+    let url = match source {
+        ImageSource::App { appid, image } => SteamImageEndpoint::App { appid, image }.url(),
+        ImageSource::Achievement { achievement, achieved: true } => achievement.icon.clone(),
+        ImageSource::Achievement { achievement, achieved: false } => achievement.icongray.clone(),
+        ImageSource::Avatar { player, size } => player.avatar_url(size).to_owned(),
+    };
+
+    Some(url).filter(|url| !url.is_empty())
+}
+
+/// Checks status of image response, returns `None` for missing images.
+pub(super) async fn check_image_status(res: Response, capture: &Option<Capture>) -> Result<Option<Response>, SasError> {
+    if res.status() == StatusCode::NOT_FOUND {
+        return Ok(None);
+    }
+
+    check_status(res, capture).await.map(Some)
+}
+
+/// Returns content type of the response.
+pub(super) fn content_type(res: &Response) -> Option<String> {
+    res.headers()
+        .get(CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .map(|value| value.to_owned())
+}
+
+/// Checks that response body is an image, e.g. not an error page served with success status.
+pub(super) fn read_image(bytes: Bytes, content_type: Option<String>) -> Result<Image, SasError> {
+    let format = ImageFormat::detect(&bytes);
+
+    check_content_type(content_type.as_deref(), format.is_some())?;
+
+    Ok(Image {
+        bytes,
+        content_type,
+        format,
+    })
+}
+
+/// Checks that content type is an image, responses without content type are checked by the data.
+fn check_content_type(content_type: Option<&str>, detected: bool) -> Result<(), SasError> {
+    let is_image = match content_type {
+        Some(content_type) => content_type.starts_with("image/"),
+        None => detected,
+    };
+
+    if !is_image {
+        error!(content_type = ?content_type);
+        return Err(SasError::UnexpectedContent {
+            content_type: content_type.map(|content_type| content_type.to_owned()),
+        });
+    }
+
+    Ok(())
+}
+
+fn check_size(size: u64, max_size: Option<u64>) -> Result<(), SasError> {
+    match max_size {
+        Some(max_size) if size > max_size => Err(SasError::ImageTooLarge { max_size }),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PNG: &[u8] = b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR";
+
+    fn unexpected(result: Result<Image, SasError>) -> Option<String> {
+        match result {
+            Err(SasError::UnexpectedContent { content_type }) => content_type,
+            other => panic!("expected unexpected content, got {:?}", other.map(|image| image.format)),
+        }
+    }
+
+    #[test]
+    fn rejects_non_image_content_types() {
+        for content_type in ["text/html; charset=UTF-8", "application/json", "application/octet-stream"] {
+            let result = read_image(Bytes::from_static(PNG), Some(content_type.to_owned()));
+
+            assert_eq!(unexpected(result).as_deref(), Some(content_type));
+        }
+    }
+
+    #[test]
+    fn accepts_image_content_type_with_unknown_format() {
+        let image = read_image(Bytes::from_static(b"BM\0\0"), Some("image/bmp".to_owned())).unwrap();
+
+        assert_eq!(image.content_type.as_deref(), Some("image/bmp"));
+        assert_eq!(image.format, None);
+    }
+
+    #[test]
+    fn sniffs_images_without_content_type() {
+        let image = read_image(Bytes::from_static(PNG), None).unwrap();
+
+        assert_eq!(image.content_type, None);
+        assert_eq!(image.format, Some(ImageFormat::Png));
+    }
+
+    #[test]
+    fn rejects_unknown_data_without_content_type() {
+        let result = read_image(Bytes::from_static(b"<!DOCTYPE html><html>"), None);
+
+        assert_eq!(unexpected(result), None);
+    }
+
+    #[test]
+    fn reports_content_type_in_message() {
+        let error = check_content_type(Some("text/html"), false).unwrap_err();
+        assert_eq!(error.to_string(), "Expected image, received text/html");
+
+        let error = check_content_type(None, false).unwrap_err();
+        assert_eq!(error.to_string(), "Expected image, received unknown content");
+    }
+}
@@ -0,0 +1,127 @@
+use futures::{stream, Stream, TryStreamExt};
+
+use crate::{
+    entity::{Leaderboard, LeaderboardEntries, LeaderboardEntry, LeaderboardRange},
+    error::SasError,
+    AppId,
+};
+
+use super::{
+    endpoint::SteamEndpoint,
+    response::{LeaderboardEntryInformation, Leaderboards},
+    SteamClient,
+};
+
+impl SteamClient {
+    /// Returns list of leaderboards of given app.
+    ///
+    /// Needs publisher key.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use steam_api_client::{AppId, SteamClient};
+    ///
+    /// # tokio_test::block_on(async {
+    /// let client = SteamClient::new();
+    /// match client.get_leaderboards(AppId(480)).await {
+    ///     Ok(leaderboards) => {/*List of leaderboards*/},
+    ///     Err(e) => {/*Error*/}
+    /// }
+    /// # });
+    /// ```
+    #[tracing::instrument(skip(self))]
+    pub async fn get_leaderboards(&self, appid: AppId) -> Result<Vec<Leaderboard>, SasError> {
+        let url = SteamEndpoint::GetLeaderboardsForGame { appid }.url(self)?;
+
+        let res: Leaderboards = self.get_response(url).await?;
+
+        Ok(res.leaderboards)
+    }
+
+    /// Returns entries of given leaderboard in given range.
+    ///
+    /// Needs publisher key.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use steam_api_client::{AppId, SteamClient};
+    /// use steam_api_client::entity::LeaderboardRange;
+    ///
+    /// # tokio_test::block_on(async {
+    /// let client = SteamClient::new();
+    /// let range = LeaderboardRange::AroundUser { steam_id: "playerId", before: 5, after: 5 };
+    /// match client.get_leaderboard_entries(AppId(480), 1234, range).await {
+    ///     Ok(entries) => {/*List of entries*/},
+    ///     Err(e) => {/*Error*/}
+    /// }
+    /// # });
+    /// ```
+    #[tracing::instrument(skip(self))]
+    pub async fn get_leaderboard_entries(
+        &self,
+        appid: AppId,
+        leaderboard_id: u64,
+        range: LeaderboardRange<'_>,
+    ) -> Result<LeaderboardEntries, SasError> {
+        let url = SteamEndpoint::GetLeaderboardEntries {
+            appid,
+            leaderboard_id,
+            range,
+        }
+        .url(self)?;
+
+        let res: LeaderboardEntryInformation = self.get_typed(url).await?;
+
+        Ok(res.entries)
+    }
+
+    /// Returns stream of all entries of given leaderboard, ordered by rank.
+    ///
+    /// Entries are requested in pages of `page_size`. Needs publisher key.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use futures::TryStreamExt;
+    /// use steam_api_client::{AppId, SteamClient};
+    ///
+    /// # tokio_test::block_on(async {
+    /// let client = SteamClient::new();
+    /// let entries = client.leaderboard_entries_stream(AppId(480), 1234, 100);
+    /// match entries.try_collect::<Vec<_>>().await {
+    ///     Ok(entries) => {/*All entries*/},
+    ///     Err(e) => {/*Error*/}
+    /// }
+    /// # });
+    /// ```
+    pub fn leaderboard_entries_stream(
+        &self,
+        appid: AppId,
+        leaderboard_id: u64,
+        page_size: u32,
+    ) -> impl Stream<Item = Result<LeaderboardEntry, SasError>> + '_ {
+        let page_size = page_size.max(1);
+
+        stream::try_unfold(Some(1), move |start: Option<u32>| async move {
+            let start = match start {
+                Some(start) => start,
+                None => return Ok(None),
+            };
+
+            let end = start.saturating_add(page_size - 1);
+            let page = self
+                .get_leaderboard_entries(appid, leaderboard_id, LeaderboardRange::Global { start, end })
+                .await?;
+
+            // ranks end at `u32::MAX`
+            let next = if page.entries.is_empty() || end >= page.total {
+                None
+            } else {
+                end.checked_add(1)
+            };
+
+            Ok::<_, SasError>(Some((page.entries, next)))
+        })
+        .map_ok(|entries| stream::iter(entries.into_iter().map(Ok)))
+        .try_flatten()
+    }
+}
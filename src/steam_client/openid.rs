@@ -0,0 +1,154 @@
+use std::collections::HashMap;
+
+use reqwest::Url;
+use tracing::info_span;
+use tracing_futures::Instrument;
+
+use crate::{error::SasError, SteamId};
+
+use super::{check_status, host::Host, SteamClient};
+
+const OPENID_NS: &str = "http://specs.openid.net/auth/2.0";
+const IDENTIFIER_SELECT: &str = "http://specs.openid.net/auth/2.0/identifier_select";
+/// Fields which have to be listed in `openid.signed`, without the `openid.` prefix.
+const SIGNED_FIELDS: [&str; 6] = [
+    "claimed_id",
+    "identity",
+    "return_to",
+    "op_endpoint",
+    "response_nonce",
+    "assoc_handle",
+];
+
+impl SteamClient {
+    /// Returns url of Steam login page, to which the user should be redirected.
+    ///
+    /// After login, Steam redirects the user to `return_to` with `openid.*` query parameters,
+    /// which should be checked with [SteamClient::verify_openid_login].
+    ///
+    /// # Examples
+    /// ```rust
+    /// use steam_api_client::SteamClient;
+    ///
+    /// let client = SteamClient::new();
+    /// let url = client.openid_login_url("https://example.com", "https://example.com/auth/callback");
+    /// ```
+    pub fn openid_login_url(&self, realm: &str, return_to: &str) -> Result<String, SasError> {
+        let mut url = self.openid_endpoint()?;
+        url.query_pairs_mut()
+            .append_pair("openid.ns", OPENID_NS)
+            .append_pair("openid.mode", "checkid_setup")
+            .append_pair("openid.return_to", return_to)
+            .append_pair("openid.realm", realm)
+            .append_pair("openid.identity", IDENTIFIER_SELECT)
+            .append_pair("openid.claimed_id", IDENTIFIER_SELECT);
+
+        Ok(url.into())
+    }
+
+    /// Verifies `openid.*` query parameters Steam sent to `return_to` after login, and returns id of the logged in player.
+    ///
+    /// Parameters are validated locally first, then confirmed by Steam.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use std::collections::HashMap;
+    /// use steam_api_client::SteamClient;
+    ///
+    /// # tokio_test::block_on(async {
+    /// let client = SteamClient::new();
+    /// # let params = HashMap::new();
+    /// match client.verify_openid_login("https://example.com/auth/callback", &params).await {
+    ///     Ok(id) => {/*Player logged in*/},
+    ///     Err(e) => {/*Login not valid*/}
+    /// }
+    /// # });
+    /// ```
+    #[tracing::instrument(skip(self, params))]
+    pub async fn verify_openid_login(
+        &self,
+        return_to: &str,
+        params: &HashMap<String, String>,
+    ) -> Result<SteamId, SasError> {
+        let param = |name: &'static str| {
+            params
+                .get(name)
+                .map(|v| v.as_str())
+                .ok_or_else(|| SasError::OpenIdError(format!("Missing parameter {}", name).into()))
+        };
+
+        if param("openid.ns")? != OPENID_NS {
+            return Err(SasError::OpenIdError("Unsupported OpenID version".into()));
+        }
+        if param("openid.mode")? != "id_res" {
+            return Err(SasError::OpenIdError("Login was not successful".into()));
+        }
+
+        let endpoint = self.openid_endpoint()?;
+        if param("openid.op_endpoint")? != endpoint.as_str() {
+            return Err(SasError::OpenIdError("Unexpected OpenID provider".into()));
+        }
+        if param("openid.return_to")? != return_to {
+            return Err(SasError::OpenIdError("Return url does not match".into()));
+        }
+
+        let claimed_id = param("openid.claimed_id")?;
+        if param("openid.identity")? != claimed_id {
+            return Err(SasError::OpenIdError("Identity does not match claimed id".into()));
+        }
+        let id_prefix = format!("{}/openid/id/", self.hosts.get(Host::Community));
+        let steam_id: SteamId = claimed_id
+            .strip_prefix(&id_prefix)
+            .and_then(|id| id.parse().ok())
+            .ok_or_else(|| SasError::OpenIdError("Invalid claimed id".into()))?;
+
+        for name in ["openid.assoc_handle", "openid.signed", "openid.sig", "openid.response_nonce"] {
+            param(name)?;
+        }
+
+        // values checked above are trusted only when covered by the signature
+        let signed: Vec<&str> = param("openid.signed")?.split(',').collect();
+        if let Some(name) = SIGNED_FIELDS.iter().find(|name| !signed.contains(name)) {
+            return Err(SasError::OpenIdError(format!("Parameter openid.{} is not signed", name).into()));
+        }
+
+        // ask Steam to check the signature, with all parameters sent back unchanged
+        let mut form: Vec<(&str, &str)> = params
+            .iter()
+            .filter(|(k, _)| k.starts_with("openid.") && k.as_str() != "openid.mode")
+            .map(|(k, v)| (k.as_str(), v.as_str()))
+            .collect();
+        form.push(("openid.mode", "check_authentication"));
+
+        let res = self
+            .client
+            .post(endpoint)
+            .form(&form)
+            .send()
+            .instrument(info_span!("OpenID request"))
+            .await?;
+
+        let capture = self.capture(&res);
+        let res = check_status(res, &capture).await?;
+
+        let body = res.text().await?;
+
+        // response is in key-value form, one `key:value` pair per line
+        let is_valid = body
+            .lines()
+            .filter_map(|line| line.split_once(':'))
+            .any(|(key, value)| key == "is_valid" && value == "true");
+
+        if !is_valid {
+            return Err(SasError::OpenIdError("Steam rejected the login".into()));
+        }
+
+        Ok(steam_id)
+    }
+
+    fn openid_endpoint(&self) -> Result<Url, SasError> {
+        let base = self.hosts.get(Host::Community);
+        Url::parse(&format!("{}/openid/login", base))
+            .map_err(|_| SasError::InvalidUrl { url: base.to_owned() })
+    }
+}
@@ -0,0 +1,189 @@
+use crate::{
+    entity::{Image, ProfileItem, ProfileItemsEquipped},
+    error::SasError,
+    AppId, SteamId,
+};
+
+use super::{
+    endpoint::SteamEndpoint,
+    response::{EquippedItem, Lender},
+    SteamClient,
+};
+
+impl SteamClient {
+    /// Returns ID of the lender, when the player is playing given app borrowed with Family Sharing.
+    ///
+    /// Returns `None` when the player owns the app or is not playing it.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use steam_api_client::{AppId, SteamClient, SteamId};
+    ///
+    /// # tokio_test::block_on(async {
+    /// let client = SteamClient::new();
+    /// match client.is_playing_shared_game(SteamId(76561197960287930), AppId(440)).await {
+    ///     Ok(Some(lender)) => {/*Borrowed copy*/},
+    ///     Ok(None) => {/*Owned copy*/},
+    ///     Err(e) => {/*Error*/}
+    /// }
+    /// # });
+    /// ```
+    #[tracing::instrument(skip(self))]
+    pub async fn is_playing_shared_game(&self, steam_id: SteamId, appid: AppId) -> Result<Option<SteamId>, SasError> {
+        let url = SteamEndpoint::IsPlayingSharedGame { steam_id, appid }.url(self)?;
+
+        let res: Lender = self.get_response(url).await?;
+
+        Ok(Some(res.lender_steamid).filter(|lender| lender.0 != 0))
+    }
+
+    /// Returns profile customization items equipped by given player.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use steam_api_client::{SteamClient, SteamId};
+    ///
+    /// # tokio_test::block_on(async {
+    /// let client = SteamClient::new();
+    /// match client.get_profile_items_equipped(SteamId(76561197960287930), Some("english")).await {
+    ///     Ok(items) => {/*Equipped items*/},
+    ///     Err(e) => {/*Error*/}
+    /// }
+    /// # });
+    /// ```
+    #[tracing::instrument(skip(self))]
+    pub async fn get_profile_items_equipped(
+        &self,
+        steam_id: SteamId,
+        lang: Option<&str>,
+    ) -> Result<ProfileItemsEquipped, SasError> {
+        let url = SteamEndpoint::GetProfileItemsEquipped { steam_id, lang }.url(self)?;
+
+        self.get_response(url).await
+    }
+
+    /// Returns animated avatar of given player, `None` when nothing is equipped.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use steam_api_client::{SteamClient, SteamId};
+    ///
+    /// # tokio_test::block_on(async {
+    /// let client = SteamClient::new();
+    /// match client.get_animated_avatar(SteamId(76561197960287930), None).await {
+    ///     Ok(Some(item)) => {/*Equipped item*/},
+    ///     Ok(None) => {/*Nothing equipped*/},
+    ///     Err(e) => {/*Error*/}
+    /// }
+    /// # });
+    /// ```
+    #[tracing::instrument(skip(self))]
+    pub async fn get_animated_avatar(&self, steam_id: SteamId, lang: Option<&str>) -> Result<Option<ProfileItem>, SasError> {
+        let url = SteamEndpoint::GetAnimatedAvatar { steam_id, lang }.url(self)?;
+
+        let res: EquippedItem = self.get_response(url).await?;
+
+        Ok(res.item)
+    }
+
+    /// Returns avatar frame of given player, `None` when nothing is equipped.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use steam_api_client::{SteamClient, SteamId};
+    ///
+    /// # tokio_test::block_on(async {
+    /// let client = SteamClient::new();
+    /// match client.get_avatar_frame(SteamId(76561197960287930), None).await {
+    ///     Ok(Some(item)) => {/*Equipped item*/},
+    ///     Ok(None) => {/*Nothing equipped*/},
+    ///     Err(e) => {/*Error*/}
+    /// }
+    /// # });
+    /// ```
+    #[tracing::instrument(skip(self))]
+    pub async fn get_avatar_frame(&self, steam_id: SteamId, lang: Option<&str>) -> Result<Option<ProfileItem>, SasError> {
+        let url = SteamEndpoint::GetAvatarFrame { steam_id, lang }.url(self)?;
+
+        let res: EquippedItem = self.get_response(url).await?;
+
+        Ok(res.item)
+    }
+
+    /// Returns mini profile background of given player, `None` when nothing is equipped.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use steam_api_client::{SteamClient, SteamId};
+    ///
+    /// # tokio_test::block_on(async {
+    /// let client = SteamClient::new();
+    /// match client.get_mini_profile_background(SteamId(76561197960287930), None).await {
+    ///     Ok(Some(item)) => {/*Equipped item*/},
+    ///     Ok(None) => {/*Nothing equipped*/},
+    ///     Err(e) => {/*Error*/}
+    /// }
+    /// # });
+    /// ```
+    #[tracing::instrument(skip(self))]
+    pub async fn get_mini_profile_background(&self, steam_id: SteamId, lang: Option<&str>) -> Result<Option<ProfileItem>, SasError> {
+        let url = SteamEndpoint::GetMiniProfileBackground { steam_id, lang }.url(self)?;
+
+        let res: EquippedItem = self.get_response(url).await?;
+
+        Ok(res.item)
+    }
+
+    /// Returns profile background of given player, `None` when nothing is equipped.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use steam_api_client::{SteamClient, SteamId};
+    ///
+    /// # tokio_test::block_on(async {
+    /// let client = SteamClient::new();
+    /// match client.get_profile_background(SteamId(76561197960287930), None).await {
+    ///     Ok(Some(item)) => {/*Equipped item*/},
+    ///     Ok(None) => {/*Nothing equipped*/},
+    ///     Err(e) => {/*Error*/}
+    /// }
+    /// # });
+    /// ```
+    #[tracing::instrument(skip(self))]
+    pub async fn get_profile_background(&self, steam_id: SteamId, lang: Option<&str>) -> Result<Option<ProfileItem>, SasError> {
+        let url = SteamEndpoint::GetProfileBackground { steam_id, lang }.url(self)?;
+
+        let res: EquippedItem = self.get_response(url).await?;
+
+        Ok(res.item)
+    }
+
+    /// Returns large image of given profile item, or small one when the item has no large image.
+    ///
+    /// Returns `None` for items without image and for missing images.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use steam_api_client::{SteamClient, SteamId};
+    ///
+    /// # tokio_test::block_on(async {
+    /// let client = SteamClient::new();
+    /// if let Ok(Some(item)) = client.get_profile_background(SteamId(76561197960287930), None).await {
+    ///     match client.get_profile_item_image(&item).await {
+    ///         Ok(Some(image)) => {/*Item image with format*/},
+    ///         Ok(None) => {/*No image*/},
+    ///         Err(e) => {/*Error*/}
+    ///     }
+    /// }
+    /// # });
+    /// ```
+    #[tracing::instrument(skip(self))]
+    pub async fn get_profile_item_image(&self, item: &ProfileItem) -> Result<Option<Image>, SasError> {
+        let url = match item.image_large_url().or_else(|| item.image_small_url()) {
+            Some(url) => url,
+            None => return Ok(None),
+        };
+
+        self.get_optional_image(&url).await
+    }
+}
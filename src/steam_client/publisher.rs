@@ -0,0 +1,129 @@
+use crate::{
+    entity::{AppBeta, AppOwnership, UserTicket},
+    error::SasError,
+    AppId,
+};
+
+use super::{
+    endpoint::SteamEndpoint,
+    response::{Betas, Envelope, OwnedApps, Ownership, Ticket},
+    SteamClient,
+};
+
+impl SteamClient {
+    /// Returns ownership of given app by the player.
+    ///
+    /// Needs publisher key, the app has to belong to the publisher.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use steam_api_client::{AppId, SteamClient};
+    ///
+    /// # tokio_test::block_on(async {
+    /// let mut client = SteamClient::new();
+    /// client.set_publisher_key("publisherKey".to_owned());
+    /// match client.check_app_ownership("playerId", AppId(480)).await {
+    ///     Ok(ownership) => {/*Ownership info*/},
+    ///     Err(e) => {/*Error*/}
+    /// }
+    /// # });
+    /// ```
+    #[tracing::instrument(skip(self))]
+    pub async fn check_app_ownership(&self, id: &str, appid: AppId) -> Result<AppOwnership, SasError> {
+        let url = SteamEndpoint::CheckAppOwnership { steam_id: id, appid }.url(self)?;
+
+        let res: Ownership<AppOwnership> = self.get_typed(url).await?;
+
+        Ok(res.appownership)
+    }
+
+    /// Returns ownership of all publisher's apps by the player.
+    ///
+    /// Needs publisher key.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use steam_api_client::SteamClient;
+    ///
+    /// # tokio_test::block_on(async {
+    /// let mut client = SteamClient::new();
+    /// client.set_publisher_key("publisherKey".to_owned());
+    /// match client.get_publisher_app_ownership("playerId").await {
+    ///     Ok(apps) => {/*List of apps*/},
+    ///     Err(e) => {/*Error*/}
+    /// }
+    /// # });
+    /// ```
+    #[tracing::instrument(skip(self))]
+    pub async fn get_publisher_app_ownership(&self, id: &str) -> Result<Vec<AppOwnership>, SasError> {
+        let url = SteamEndpoint::GetPublisherAppOwnership { steam_id: id }.url(self)?;
+
+        let res: Ownership<OwnedApps> = self.get_typed(url).await?;
+
+        Ok(res.appownership.apps)
+    }
+
+    /// Validates session ticket for given app.
+    ///
+    /// Ticket is the raw ticket from `ISteamUser::GetAuthTicketForWebApi` or `GetAuthSessionTicket`.
+    /// Identity has to match the one used when the ticket was created, if any.
+    /// Rejected tickets return [SasError::InvalidTicket]. Needs publisher key.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use steam_api_client::{AppId, SasError, SteamClient};
+    ///
+    /// # tokio_test::block_on(async {
+    /// let mut client = SteamClient::new();
+    /// client.set_publisher_key("publisherKey".to_owned());
+    /// # let ticket = [0x14, 0x00, 0x00, 0x00];
+    /// match client.authenticate_user_ticket(AppId(480), &ticket, Some("gameServer")).await {
+    ///     Ok(ticket) => {/*Valid ticket*/},
+    ///     Err(SasError::InvalidTicket { .. }) => {/*Ticket rejected*/},
+    ///     Err(e) => {/*Error*/}
+    /// }
+    /// # });
+    /// ```
+    #[tracing::instrument(skip(self, ticket))]
+    pub async fn authenticate_user_ticket(
+        &self,
+        appid: AppId,
+        ticket: &[u8],
+        identity: Option<&str>,
+    ) -> Result<UserTicket, SasError> {
+        let url = SteamEndpoint::AuthenticateUserTicket { appid, ticket, identity }.url(self)?;
+
+        self.get_checked(url, |res: Envelope<Ticket>| res.response.into_ticket())
+            .await
+    }
+
+    /// Returns beta branches of given app, sorted by name.
+    ///
+    /// Needs publisher key.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use steam_api_client::{AppId, SteamClient};
+    ///
+    /// # tokio_test::block_on(async {
+    /// let mut client = SteamClient::new();
+    /// client.set_publisher_key("publisherKey".to_owned());
+    /// match client.get_app_betas(AppId(480)).await {
+    ///     Ok(betas) => {/*List of branches*/},
+    ///     Err(e) => {/*Error*/}
+    /// }
+    /// # });
+    /// ```
+    #[tracing::instrument(skip(self))]
+    pub async fn get_app_betas(&self, appid: AppId) -> Result<Vec<AppBeta>, SasError> {
+        let url = SteamEndpoint::GetAppBetas { appid }.url(self)?;
+
+        let res: Betas = self.get_response(url).await?;
+
+        Ok(res
+            .betas
+            .into_iter()
+            .map(|(name, beta)| AppBeta { name, ..beta })
+            .collect())
+    }
+}
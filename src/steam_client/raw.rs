@@ -0,0 +1,181 @@
+use std::fmt::{Debug, Display, Formatter};
+
+use serde::{de::DeserializeOwned, Serialize};
+use tracing::info_span;
+use tracing_futures::Instrument;
+
+use crate::{
+    auth::{is_secret, CREDENTIAL_PARAMS},
+    error::SasError,
+};
+
+use super::{
+    check_status,
+    endpoint::{base_url, Credential},
+    from_slice,
+    host::Host,
+    response::Json,
+    SteamClient,
+};
+
+/// Call of any Web API method, for methods without dedicated support in [SteamClient].
+///
+/// Credentials are added by the client, api key is preferred over access token.
+/// Calls to [Host::Partner] use publisher key instead, as the partner host rejects regular api keys.
+/// Calls to [Host::Community] and [Host::Store] are sent without credentials.
+///
+/// `key` or `access_token` parameters added to the call replace the client's credentials.
+///
+/// # Examples
+/// ```rust
+/// use steam_api_client::RawCall;
+///
+/// let call = RawCall::get("IPlayerService", "GetSteamLevel", 1).param("steamid", "76561197960287930");
+/// ```
+#[derive(Clone)]
+pub struct RawCall<'a> {
+    interface: &'a str,
+    method: &'a str,
+    version: u32,
+    post: bool,
+    host: Host,
+    params: Vec<(String, String)>,
+    unwrap_response: bool,
+}
+
+impl Debug for RawCall<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        // credentials passed as parameters are never printed
+        let params: Vec<(&str, &str)> = self
+            .params
+            .iter()
+            .map(|(name, value)| match is_secret(name) {
+                true => (name.as_str(), ".."),
+                false => (name.as_str(), value.as_str()),
+            })
+            .collect();
+
+        f.debug_struct("RawCall")
+            .field("interface", &self.interface)
+            .field("method", &self.method)
+            .field("version", &self.version)
+            .field("post", &self.post)
+            .field("host", &self.host)
+            .field("params", &params)
+            .field("unwrap_response", &self.unwrap_response)
+            .finish()
+    }
+}
+
+impl<'a> RawCall<'a> {
+    /// Creates GET call of given method.
+    pub fn get(interface: &'a str, method: &'a str, version: u32) -> Self {
+        Self {
+            interface,
+            method,
+            version,
+            post: false,
+            host: Host::Api,
+            params: Vec::new(),
+            unwrap_response: true,
+        }
+    }
+
+    /// Creates POST call of given method, parameters are sent as form.
+    pub fn post(interface: &'a str, method: &'a str, version: u32) -> Self {
+        Self {
+            post: true,
+            ..Self::get(interface, method, version)
+        }
+    }
+
+    /// Adds parameter.
+    pub fn param(mut self, name: &str, value: impl Display) -> Self {
+        self.params.push((name.to_owned(), value.to_string()));
+        self
+    }
+
+    /// Adds parameters serialized to `input_json` parameter, used by service interfaces.
+    ///
+    /// Fails when given input cannot be serialized to JSON.
+    pub fn input_json(self, input: &impl Serialize) -> Result<Self, serde_json::Error> {
+        let input = serde_json::to_string(input)?;
+        Ok(self.param("input_json", input))
+    }
+
+    /// Sends the call to given host, [Host::Api] by default.
+    pub fn host(mut self, host: Host) -> Self {
+        self.host = host;
+        self
+    }
+
+    /// Sets whether to return content of the `response` object instead of the whole body, `true` by default.
+    ///
+    /// Bodies without `response` object are returned whole.
+    pub fn unwrap_response(mut self, unwrap_response: bool) -> Self {
+        self.unwrap_response = unwrap_response;
+        self
+    }
+}
+
+impl SteamClient {
+    /// Calls any Web API method, returning the response as given type.
+    ///
+    /// Use [serde_json::Value] as the type for untyped response.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use serde_json::Value;
+    /// use steam_api_client::{RawCall, SteamClient};
+    ///
+    /// # tokio_test::block_on(async {
+    /// let client = SteamClient::new();
+    /// let call = RawCall::get("IPlayerService", "GetSteamLevel", 1).param("steamid", "playerId");
+    /// match client.call_raw::<Value>(call).await {
+    ///     Ok(response) => {/*Response*/},
+    ///     Err(e) => {/*Error*/}
+    /// }
+    /// # });
+    /// ```
+    #[tracing::instrument(skip(self))]
+    pub async fn call_raw<T: DeserializeOwned>(&self, call: RawCall<'_>) -> Result<T, SasError> {
+        let path = format!("/{}/{}/v{}/", call.interface, call.method, call.version);
+
+        // credentials are sent only to Web API hosts, unless the call brings its own
+        let credential = match call.host {
+            _ if call.params.iter().any(|(name, _)| CREDENTIAL_PARAMS.contains(&name.as_str())) => Credential::None,
+            Host::Api => Credential::Optional,
+            Host::Partner => Credential::PublisherKey,
+            Host::Community | Host::Store => Credential::None,
+        };
+        let credential = credential.param(self, &path)?;
+
+        let mut params: Vec<(&str, &str)> = Vec::with_capacity(call.params.len() + 1);
+        if let Some(credential) = credential {
+            params.push(credential);
+        }
+        params.extend(call.params.iter().map(|(name, value)| (name.as_str(), value.as_str())));
+
+        let url = base_url(self, call.host, &path)?;
+        let req = if call.post {
+            self.client.post(url).form(&params)
+        } else {
+            self.client.get(url).query(&params)
+        };
+
+        let res = req.send().instrument(info_span!("API request")).await?;
+
+        let capture = self.capture(&res);
+        let res = check_status(res, &capture).await?;
+
+        let body = res.bytes().instrument(info_span!("reading from JSON")).await?;
+        let value = from_slice(&path, &capture, &body).await?;
+        let mut res = Json::new(path, capture, body, value);
+
+        if call.unwrap_response && !res["response"].is_null() {
+            return res.take("/response").await;
+        }
+
+        res.take("").await
+    }
+}
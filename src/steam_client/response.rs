@@ -0,0 +1,547 @@
+//! Envelopes of Web API responses, deserialized directly from response body.
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    ops::{Index, IndexMut},
+};
+
+use bytes::Bytes;
+use reqwest::StatusCode;
+use serde::{de::DeserializeOwned, Deserialize};
+use serde_json::Value;
+use tracing::error;
+
+use crate::{
+    entity::{
+        AchievementPercentageSchema, ApiInterface, AppBeta, AppDetails, AppOwnership, AssetClassInfo, AssetPrice,
+        AssetPrices, GameSchema, GameServer, GameStats, Leaderboard, LeaderboardEntries, Player, PlayerStatsSchema, ProfileItem,
+        ServerAtAddress, SharedApp, TradeOffer, TradeOfferDetail, UserGroup, UserTicket, Visibility, WishlistItem,
+    },
+    error::SasError,
+    SteamId,
+};
+
+use super::diagnostics::{attach, Capture};
+
+/// JSON body of a response, for raw calls where the caller picks the type.
+///
+/// Keeps the endpoint and raw body for decode errors.
+pub struct Json {
+    endpoint: String,
+    capture: Option<Capture>,
+    body: Bytes,
+    value: Value,
+}
+
+impl Json {
+    pub fn new(endpoint: String, capture: Option<Capture>, body: Bytes, value: Value) -> Self {
+        Self {
+            endpoint,
+            capture,
+            body,
+            value,
+        }
+    }
+
+    /// Takes value at given JSON pointer and deserializes it, missing values are taken as null.
+    pub async fn take<T: DeserializeOwned>(&mut self, pointer: &str) -> Result<T, SasError> {
+        let value = self.value.pointer_mut(pointer).map(Value::take).unwrap_or_default();
+
+        match serde_json::from_value(value) {
+            Ok(v) => Ok(v),
+            Err(e) => {
+                error!(error = ?e);
+                let error = SasError::decode(&self.endpoint, e, &self.body);
+                Err(attach(&self.capture, error, &self.body).await)
+            }
+        }
+    }
+}
+
+impl Index<&str> for Json {
+    type Output = Value;
+
+    fn index(&self, index: &str) -> &Value {
+        &self.value[index]
+    }
+}
+
+impl IndexMut<&str> for Json {
+    fn index_mut(&mut self, index: &str) -> &mut Value {
+        &mut self.value[index]
+    }
+}
+
+/// Reason for not converting a deserialized envelope into the result.
+pub enum Rejected {
+    /// Body does not have the expected shape, reported as decode error with given message
+    Malformed(&'static str),
+    /// Steam reported failure inside a successful response
+    Failed(SasError),
+}
+
+impl Rejected {
+    /// Failure reported by Steam with given message.
+    fn failed(msg: String) -> Self {
+        Self::Failed(SasError::SteamApiError {
+            msg: msg.into(),
+            status: StatusCode::BAD_GATEWAY,
+            diagnostics: None,
+        })
+    }
+}
+
+impl From<&'static str> for Rejected {
+    fn from(msg: &'static str) -> Self {
+        Self::Malformed(msg)
+    }
+}
+
+/// Body of service methods, with the result in `response` object.
+#[derive(Deserialize)]
+pub struct Envelope<T> {
+    pub response: T,
+}
+
+/// Game lists of private profiles are empty `response` objects, without the game count.
+#[derive(Deserialize)]
+pub struct Games<T> {
+    #[serde(alias = "total_count")]
+    pub game_count: Option<u32>,
+    #[serde(default = "Vec::new")]
+    pub games: Vec<T>,
+}
+
+impl<T> Games<T> {
+    pub fn visibility(self) -> Visibility<Vec<T>> {
+        match (self.game_count, self.games.is_empty()) {
+            (None, true) => Visibility::Private,
+            _ => Visibility::Public(self.games),
+        }
+    }
+}
+
+/// Single profile item, each method names the slot differently.
+#[derive(Deserialize)]
+pub struct EquippedItem {
+    #[serde(
+        default,
+        alias = "avatar",
+        alias = "avatar_frame",
+        alias = "profile_background",
+        deserialize_with = "crate::entity::equipped"
+    )]
+    pub item: Option<ProfileItem>,
+}
+
+#[derive(Deserialize)]
+pub struct Players {
+    pub players: Vec<Player>,
+}
+
+#[derive(Deserialize)]
+pub struct PlayerStats {
+    pub playerstats: Option<PlayerStatsSchema>,
+}
+
+#[derive(Deserialize)]
+pub struct AchievementPercentages {
+    pub achievementpercentages: Achievements,
+}
+
+#[derive(Deserialize)]
+pub struct Achievements {
+    pub achievements: Vec<AchievementPercentageSchema>,
+}
+
+#[derive(Deserialize)]
+pub struct Schema {
+    pub game: Option<SchemaGame>,
+}
+
+impl Schema {
+    pub fn into_game_schema(self) -> Result<Option<GameSchema>, Rejected> {
+        match self.game {
+            Some(SchemaGame {
+                game_name: None,
+                game_version: None,
+                stats: None,
+            }) => Ok(None),
+            Some(SchemaGame {
+                game_name: Some(game_name),
+                game_version: Some(game_version),
+                stats: Some(stats),
+            }) => Ok(Some(GameSchema {
+                game_name,
+                game_version,
+                stats,
+            })),
+            Some(_) => Err("incomplete game schema".into()),
+            None => Err("missing game".into()),
+        }
+    }
+}
+
+/// Apps without schema return empty `game` object, so all fields are optional.
+#[derive(Deserialize)]
+pub struct SchemaGame {
+    #[serde(rename = "gameName")]
+    pub game_name: Option<String>,
+    #[serde(rename = "gameVersion")]
+    pub game_version: Option<String>,
+    #[serde(rename = "availableGameStats")]
+    pub stats: Option<GameStats>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+#[allow(dead_code)]
+pub enum VanityUrl {
+    Ok { steamid: String, success: i32 },
+    NotFound { message: String, success: i32 },
+}
+
+#[derive(Deserialize)]
+pub struct Lender {
+    pub lender_steamid: SteamId,
+}
+
+/// Economy responses report failures with `success: false` and `error` message inside `result`.
+#[derive(Deserialize)]
+pub struct Economy<T> {
+    pub result: EconomyResult<T>,
+}
+
+#[derive(Deserialize)]
+pub struct EconomyResult<T> {
+    pub success: Option<bool>,
+    pub error: Option<String>,
+    #[serde(flatten)]
+    pub data: T,
+}
+
+impl<T> Economy<T> {
+    pub fn into_result(self) -> Result<T, Rejected> {
+        match self.result {
+            EconomyResult { success: Some(true), data, .. } => Ok(data),
+            EconomyResult { error: Some(error), .. } => Err(Rejected::failed(error)),
+            _ => Err("missing success flag".into()),
+        }
+    }
+}
+
+/// Prices are left out of failed responses.
+#[derive(Deserialize)]
+pub struct Prices {
+    pub assets: Option<Vec<AssetPrice>>,
+    #[serde(default)]
+    pub tags: HashMap<String, String>,
+}
+
+impl Prices {
+    pub fn into_prices(self) -> Result<AssetPrices, Rejected> {
+        match self.assets {
+            Some(assets) => Ok(AssetPrices { assets, tags: self.tags }),
+            None => Err("missing assets".into()),
+        }
+    }
+}
+
+/// Classes are keyed by `classid` or `classid_instanceid`, next to the `success` flag.
+pub type AssetClasses = HashMap<String, AssetClassInfo>;
+
+/// Players without groups and private profiles share the `success` flag, only the error tells them apart.
+#[derive(Deserialize)]
+pub struct UserGroups {
+    pub success: Option<bool>,
+    pub error: Option<String>,
+    pub groups: Option<Vec<UserGroup>>,
+}
+
+impl UserGroups {
+    pub fn visibility(self) -> Result<Visibility<Vec<UserGroup>>, Rejected> {
+        match self {
+            UserGroups {
+                success: Some(true),
+                groups: Some(groups),
+                ..
+            } => Ok(Visibility::Public(groups)),
+            UserGroups { success: Some(true), .. } => Err("missing groups".into()),
+            // same flag is used for unknown players, only privacy errors mean private profile
+            UserGroups {
+                success: Some(false),
+                error: Some(error),
+                ..
+            } if error.to_lowercase().contains("private") => Ok(Visibility::Private),
+            UserGroups {
+                success: Some(false),
+                error: Some(error),
+                ..
+            } => Err(Rejected::failed(error)),
+            UserGroups { success: Some(false), .. } => Err("missing error message".into()),
+            UserGroups { success: None, .. } => Err("missing success flag".into()),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+pub struct Leaderboards {
+    pub leaderboards: Vec<Leaderboard>,
+}
+
+#[derive(Deserialize)]
+pub struct LeaderboardEntryInformation {
+    #[serde(rename = "leaderboardEntryInformation")]
+    pub entries: LeaderboardEntries,
+}
+
+#[derive(Deserialize)]
+pub struct ApiList {
+    pub apilist: Interfaces,
+}
+
+#[derive(Deserialize)]
+pub struct Interfaces {
+    pub interfaces: Vec<ApiInterface>,
+}
+
+/// No matching servers return empty response.
+#[derive(Deserialize)]
+pub struct Servers {
+    pub servers: Option<Vec<GameServer>>,
+}
+
+#[derive(Deserialize)]
+pub struct AddressServers {
+    #[serde(default)]
+    pub success: bool,
+    pub message: Option<String>,
+    pub servers: Option<Vec<ServerAtAddress>>,
+}
+
+impl AddressServers {
+    pub fn into_servers(self) -> Result<Vec<ServerAtAddress>, Rejected> {
+        match self {
+            AddressServers {
+                success: true,
+                servers: Some(servers),
+                ..
+            } => Ok(servers),
+            AddressServers { success: true, .. } => Err("missing servers".into()),
+            AddressServers { message, .. } => Err(Rejected::failed(
+                message.unwrap_or_else(|| "Unexpected response from Steam API".to_owned()),
+            )),
+        }
+    }
+}
+
+/// Families without shared apps return empty response.
+#[derive(Deserialize)]
+pub struct SharedApps {
+    pub apps: Option<Vec<SharedApp>>,
+}
+
+/// Store details are keyed by app id, unknown apps have only the `success` flag.
+pub type StoreDetails = HashMap<String, StoreResult>;
+
+#[derive(Deserialize)]
+pub struct StoreResult {
+    #[serde(default)]
+    pub success: bool,
+    pub data: Option<AppDetails>,
+}
+
+impl StoreResult {
+    pub fn into_details(self) -> Result<Option<AppDetails>, Rejected> {
+        match self {
+            StoreResult { success: false, .. } => Ok(None),
+            StoreResult { data: Some(data), .. } => Ok(Some(data)),
+            StoreResult { data: None, .. } => Err("missing data".into()),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+pub struct Ownership<T> {
+    pub appownership: T,
+}
+
+#[derive(Deserialize)]
+pub struct OwnedApps {
+    pub apps: Vec<AppOwnership>,
+}
+
+/// Rejected tickets have `error` object in place of `params`.
+#[derive(Deserialize)]
+pub struct Ticket {
+    pub error: Option<TicketError>,
+    pub params: Option<UserTicket>,
+}
+
+#[derive(Deserialize)]
+pub struct TicketError {
+    #[serde(default)]
+    pub errorcode: i32,
+    pub errordesc: Option<String>,
+}
+
+impl Ticket {
+    pub fn into_ticket(self) -> Result<UserTicket, Rejected> {
+        match self {
+            Ticket { error: Some(error), .. } => Err(Rejected::Failed(SasError::InvalidTicket {
+                code: error.errorcode,
+                msg: error.errordesc.unwrap_or_else(|| "Invalid ticket".to_owned()),
+            })),
+            Ticket { params: Some(params), .. } => Ok(params),
+            Ticket { params: None, .. } => Err("missing params".into()),
+        }
+    }
+}
+
+/// Betas are keyed by branch name, sorted so that branches keep the same order.
+#[derive(Deserialize)]
+pub struct Betas {
+    pub betas: BTreeMap<String, AppBeta>,
+}
+
+/// Unknown offers return empty response.
+#[derive(Deserialize)]
+pub struct Offer {
+    pub offer: Option<TradeOffer>,
+    #[serde(default)]
+    pub descriptions: Vec<AssetClassInfo>,
+}
+
+impl Offer {
+    pub fn into_detail(self) -> Option<TradeOfferDetail> {
+        self.offer.map(|offer| TradeOfferDetail {
+            offer,
+            descriptions: self.descriptions,
+        })
+    }
+}
+
+/// Private wishlists return empty response, without the items.
+#[derive(Deserialize)]
+pub struct WishlistItems {
+    pub items: Option<Vec<WishlistItem>>,
+}
+
+impl WishlistItems {
+    pub fn visibility(self) -> Visibility<Vec<WishlistItem>> {
+        match self.items {
+            Some(items) => Visibility::Public(items),
+            None => Visibility::Private,
+        }
+    }
+}
+
+/// Private wishlists return empty response, without the count.
+#[derive(Deserialize)]
+pub struct WishlistCount {
+    pub count: Option<u32>,
+}
+
+impl WishlistCount {
+    pub fn visibility(self) -> Visibility<u32> {
+        match self.count {
+            Some(count) => Visibility::Public(count),
+            None => Visibility::Private,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reason<T>(result: Result<T, Rejected>) -> String {
+        match result {
+            Ok(_) => "ok".to_owned(),
+            Err(Rejected::Malformed(msg)) => msg.to_owned(),
+            Err(Rejected::Failed(e)) => e.to_string(),
+        }
+    }
+
+    #[test]
+    fn economy_classes_skip_flags() {
+        let body = r#"{"result":{"success":true,"195151":{"classid":"195151","name":"Key","icon_url":"icon",
+            "descriptions":{"0":{"type":"html","value":"a"},"1":{"type":"text","value":"b"}},
+            "tags":{"0":{"internal_name":"Tool","name":"Tool","category":"Type"}}}}}"#;
+        let res: Economy<AssetClasses> = serde_json::from_str(body).unwrap();
+
+        let classes = res.into_result().ok().unwrap();
+        assert_eq!(classes.len(), 1);
+        assert_eq!(classes["195151"].name, "Key");
+        assert_eq!(classes["195151"].descriptions.len(), 2);
+        assert_eq!(classes["195151"].tags[0].category, "Type");
+    }
+
+    #[test]
+    fn equipped_items_skip_empty_slots() {
+        let res: EquippedItem = serde_json::from_str(r#"{"avatar_frame":{}}"#).unwrap();
+        assert!(res.item.is_none());
+
+        let res: EquippedItem = serde_json::from_str(r#"{"profile_background":null}"#).unwrap();
+        assert!(res.item.is_none());
+
+        let body = r#"{"avatar":{"communityitemid":"1234","image_small":"items/avatar.gif","appid":753,
+            "item_type":15,"item_class":15,"unknown":[1,2]}}"#;
+        let item = serde_json::from_str::<EquippedItem>(body).unwrap().item.unwrap();
+        assert_eq!(item.communityitemid, "1234");
+        assert_eq!(item.image_small.as_deref(), Some("items/avatar.gif"));
+        assert_eq!(item.item_class, 15);
+        assert_eq!(item.name, "");
+    }
+
+    #[test]
+    fn equipped_items_need_app() {
+        let res = serde_json::from_str::<EquippedItem>(r#"{"avatar":{"communityitemid":"1234"}}"#);
+        assert!(res.err().unwrap().to_string().contains("missing field `appid`"));
+    }
+
+    #[test]
+    fn betas_sorted_by_name() {
+        let body = r#"{"betas":{"public":{"BuildID":3,"Description":"","ReqPassword":false,"ReqLocalCS":false},
+            "beta":{"BuildID":2,"Description":"Beta","ReqPassword":false,"ReqLocalCS":false},
+            "internal":{"BuildID":1,"Description":"","ReqPassword":true,"ReqLocalCS":false}}}"#;
+        let res: Betas = serde_json::from_str(body).unwrap();
+
+        let names: Vec<_> = res.betas.keys().map(String::as_str).collect();
+        assert_eq!(names, ["beta", "internal", "public"]);
+    }
+
+    #[test]
+    fn economy_failures_keep_message() {
+        let body = r#"{"result":{"success":false,"error":"Invalid app"}}"#;
+        let res: Economy<Prices> = serde_json::from_str(body).unwrap();
+        assert!(reason(res.into_result()).contains("Invalid app"));
+
+        let res: Economy<Prices> = serde_json::from_str(r#"{"result":{}}"#).unwrap();
+        assert_eq!(reason(res.into_result()), "missing success flag");
+    }
+
+    #[test]
+    fn group_list_private_only_for_privacy_errors() {
+        let groups = |body: &str| serde_json::from_str::<UserGroups>(body).unwrap().visibility();
+
+        assert!(matches!(
+            groups(r#"{"success":true,"groups":[{"gid":"1"}]}"#),
+            Ok(Visibility::Public(groups)) if groups.len() == 1
+        ));
+        assert!(matches!(
+            groups(r#"{"success":false,"error":"Failed to get information about account, it may be private"}"#),
+            Ok(Visibility::Private)
+        ));
+        assert!(reason(groups(r#"{"success":false,"error":"Invalid SteamID"}"#)).contains("Invalid SteamID"));
+        assert_eq!(reason(groups(r#"{"success":false}"#)), "missing error message");
+        assert_eq!(reason(groups(r#"{"success":true}"#)), "missing groups");
+    }
+
+    #[test]
+    fn store_details_of_unknown_app() {
+        let mut res: StoreDetails = serde_json::from_str(r#"{"1":{"success":false}}"#).unwrap();
+
+        assert!(matches!(res.remove("1").unwrap().into_details(), Ok(None)));
+    }
+}
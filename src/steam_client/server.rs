@@ -0,0 +1,59 @@
+use crate::{
+    entity::{GameServer, ServerAtAddress, ServerFilter},
+    error::SasError,
+};
+
+use super::{
+    endpoint::SteamEndpoint,
+    response::{AddressServers, Envelope, Servers},
+    SteamClient,
+};
+
+impl SteamClient {
+    /// Returns list of game servers matching given filter.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use steam_api_client::{AppId, SteamClient};
+    /// use steam_api_client::entity::ServerFilter;
+    ///
+    /// # tokio_test::block_on(async {
+    /// let client = SteamClient::new();
+    /// let filter = ServerFilter::new().appid(AppId(730)).dedicated();
+    /// match client.get_server_list(&filter, Some(100)).await {
+    ///     Ok(servers) => {/*List of servers*/},
+    ///     Err(e) => {/*Error*/}
+    /// }
+    /// # });
+    /// ```
+    #[tracing::instrument(skip(self))]
+    pub async fn get_server_list(&self, filter: &ServerFilter, limit: Option<u32>) -> Result<Vec<GameServer>, SasError> {
+        let url = SteamEndpoint::GetServerList { filter, limit }.url(self)?;
+
+        let res: Servers = self.get_response(url).await?;
+
+        Ok(res.servers.unwrap_or_default())
+    }
+
+    /// Returns list of game servers running on given IP address.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use steam_api_client::SteamClient;
+    ///
+    /// # tokio_test::block_on(async {
+    /// let client = SteamClient::new();
+    /// match client.get_servers_at_address("127.0.0.1").await {
+    ///     Ok(servers) => {/*List of servers*/},
+    ///     Err(e) => {/*Error*/}
+    /// }
+    /// # });
+    /// ```
+    #[tracing::instrument(skip(self))]
+    pub async fn get_servers_at_address(&self, addr: &str) -> Result<Vec<ServerAtAddress>, SasError> {
+        let url = SteamEndpoint::GetServersAtAddress { addr }.url(self)?;
+
+        self.get_checked(url, |res: Envelope<AddressServers>| res.response.into_servers())
+            .await
+    }
+}
@@ -0,0 +1,39 @@
+use crate::{entity::AppDetails, error::SasError, AppId};
+
+use super::{endpoint::SteamStoreEndpoint, response::StoreDetails, SteamClient};
+
+impl SteamClient {
+    /// Returns store details of given app.
+    ///
+    /// Prices are in the currency of given country code, texts are in given language.
+    /// Store API is rate limited separately from Web API.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use steam_api_client::{AppId, SteamClient};
+    ///
+    /// # tokio_test::block_on(async {
+    /// let client = SteamClient::new();
+    /// match client.get_app_details(AppId(400), Some("us"), Some("english")).await {
+    ///     Ok(Some(details)) => {/*App found*/},
+    ///     Ok(None) => {/*App not in store*/},
+    ///     Err(e) => {/*Error*/}
+    /// }
+    /// # });
+    /// ```
+    #[tracing::instrument(skip(self))]
+    pub async fn get_app_details(
+        &self,
+        appid: AppId,
+        cc: Option<&str>,
+        lang: Option<&str>,
+    ) -> Result<Option<AppDetails>, SasError> {
+        let url = SteamStoreEndpoint::AppDetails { appid, cc, lang }.url(self)?;
+
+        self.get_checked(url, |mut res: StoreDetails| match res.remove(&appid.to_string()) {
+            Some(details) => details.into_details(),
+            None => Ok(None),
+        })
+        .await
+    }
+}
@@ -0,0 +1,152 @@
+use crate::{
+    entity::{
+        TradeHistory, TradeHistoryQuery, TradeHoldDurations, TradeOfferDetail, TradeOffers, TradeOffersQuery,
+        TradeOffersSummary,
+    },
+    error::SasError,
+};
+
+use super::{endpoint::SteamEndpoint, response::Offer, SteamClient};
+
+impl SteamClient {
+    /// Returns page of trade offers of the api key owner.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use steam_api_client::SteamClient;
+    /// use steam_api_client::entity::TradeOffersQuery;
+    ///
+    /// # tokio_test::block_on(async {
+    /// let client = SteamClient::new();
+    /// let mut query = TradeOffersQuery {
+    ///     get_received_offers: true,
+    ///     active_only: true,
+    ///     ..Default::default()
+    /// };
+    /// loop {
+    ///     match client.get_trade_offers(&query).await {
+    ///         Ok(offers) => {
+    ///             /*Page of offers*/
+    ///             match offers.next_cursor {
+    ///                 Some(cursor) => query.cursor = Some(cursor),
+    ///                 None => break,
+    ///             }
+    ///         }
+    ///         Err(e) => {/*Error*/ break}
+    ///     }
+    /// }
+    /// # });
+    /// ```
+    #[tracing::instrument(skip(self))]
+    pub async fn get_trade_offers(&self, query: &TradeOffersQuery) -> Result<TradeOffers, SasError> {
+        let url = SteamEndpoint::GetTradeOffers { query }.url(self)?;
+
+        self.get_response(url).await
+    }
+
+    /// Returns single trade offer.
+    ///
+    /// Item descriptions are included when language is given.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use steam_api_client::SteamClient;
+    ///
+    /// # tokio_test::block_on(async {
+    /// let client = SteamClient::new();
+    /// match client.get_trade_offer("tradeOfferId", Some("en")).await {
+    ///     Ok(Some(offer)) => {/*Offer found*/},
+    ///     Ok(None) => {/*No offer found*/},
+    ///     Err(e) => {/*Error*/}
+    /// }
+    /// # });
+    /// ```
+    #[tracing::instrument(skip(self))]
+    pub async fn get_trade_offer(
+        &self,
+        tradeofferid: &str,
+        lang: Option<&str>,
+    ) -> Result<Option<TradeOfferDetail>, SasError> {
+        let url = SteamEndpoint::GetTradeOffer { tradeofferid, lang }.url(self)?;
+
+        let res: Offer = self.get_response(url).await?;
+
+        Ok(res.into_detail())
+    }
+
+    /// Returns counts of trade offers.
+    ///
+    /// Counts of new and updated offers are relative to `time_last_visit`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use steam_api_client::SteamClient;
+    ///
+    /// # tokio_test::block_on(async {
+    /// let client = SteamClient::new();
+    /// match client.get_trade_offers_summary(None).await {
+    ///     Ok(summary) => {/*Offer counts*/},
+    ///     Err(e) => {/*Error*/}
+    /// }
+    /// # });
+    /// ```
+    #[tracing::instrument(skip(self))]
+    pub async fn get_trade_offers_summary(&self, time_last_visit: Option<u64>) -> Result<TradeOffersSummary, SasError> {
+        let url = SteamEndpoint::GetTradeOffersSummary { time_last_visit }.url(self)?;
+
+        self.get_response(url).await
+    }
+
+    /// Returns page of completed trades of the api key owner.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use steam_api_client::SteamClient;
+    /// use steam_api_client::entity::TradeHistoryQuery;
+    ///
+    /// # tokio_test::block_on(async {
+    /// let client = SteamClient::new();
+    /// match client.get_trade_history(&TradeHistoryQuery::default()).await {
+    ///     Ok(history) => {/*Page of trades*/},
+    ///     Err(e) => {/*Error*/}
+    /// }
+    /// # });
+    /// ```
+    #[tracing::instrument(skip(self))]
+    pub async fn get_trade_history(&self, query: &TradeHistoryQuery) -> Result<TradeHistory, SasError> {
+        let url = SteamEndpoint::GetTradeHistory { query }.url(self)?;
+
+        self.get_response(url).await
+    }
+
+    /// Returns trade hold durations for trade with given player.
+    ///
+    /// Access token from the trade url is needed for players who are not friends with the api key owner.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use steam_api_client::SteamClient;
+    ///
+    /// # tokio_test::block_on(async {
+    /// let client = SteamClient::new();
+    /// match client.get_trade_hold_durations("playerId", None).await {
+    ///     Ok(durations) => {/*Hold durations*/},
+    ///     Err(e) => {/*Error*/}
+    /// }
+    /// # });
+    /// ```
+    #[tracing::instrument(skip(self, trade_offer_access_token))]
+    pub async fn get_trade_hold_durations(
+        &self,
+        id: &str,
+        trade_offer_access_token: Option<&str>,
+    ) -> Result<TradeHoldDurations, SasError> {
+        let url = SteamEndpoint::GetTradeHoldDurations {
+            steam_id: id,
+            trade_offer_access_token,
+        }
+        .url(self)?;
+
+        self.get_response(url).await
+    }
+}
@@ -0,0 +1,109 @@
+use futures::{stream, StreamExt};
+
+use crate::{
+    entity::{Visibility, WishlistEntry, WishlistItem},
+    error::SasError,
+};
+
+use super::{
+    endpoint::SteamEndpoint,
+    response::{WishlistCount, WishlistItems},
+    SteamClient,
+};
+
+impl SteamClient {
+    /// Returns wishlist of given player.
+    ///
+    /// Private wishlists are [Visibility::Private].
+    ///
+    /// # Examples
+    /// ```rust
+    /// use steam_api_client::{entity::Visibility, SteamClient};
+    ///
+    /// # tokio_test::block_on(async {
+    /// let client = SteamClient::new();
+    /// match client.get_wishlist("playerId").await {
+    ///     Ok(Visibility::Public(items)) => {/*List of wishlisted apps*/},
+    ///     Ok(Visibility::Private) => {/*Wishlist is private*/},
+    ///     Err(e) => {/*Error*/}
+    /// }
+    /// # });
+    /// ```
+    #[tracing::instrument(skip(self))]
+    pub async fn get_wishlist(&self, id: &str) -> Result<Visibility<Vec<WishlistItem>>, SasError> {
+        let url = SteamEndpoint::GetWishlist { steam_id: id }.url(self)?;
+
+        let res: WishlistItems = self.get_response(url).await?;
+
+        Ok(res.visibility())
+    }
+
+    /// Returns number of apps on wishlist of given player.
+    ///
+    /// Private wishlists are [Visibility::Private].
+    ///
+    /// # Examples
+    /// ```rust
+    /// use steam_api_client::{entity::Visibility, SteamClient};
+    ///
+    /// # tokio_test::block_on(async {
+    /// let client = SteamClient::new();
+    /// match client.get_wishlist_item_count("playerId").await {
+    ///     Ok(Visibility::Public(count)) => {/*Number of wishlisted apps*/},
+    ///     Ok(Visibility::Private) => {/*Wishlist is private*/},
+    ///     Err(e) => {/*Error*/}
+    /// }
+    /// # });
+    /// ```
+    #[tracing::instrument(skip(self))]
+    pub async fn get_wishlist_item_count(&self, id: &str) -> Result<Visibility<u32>, SasError> {
+        let url = SteamEndpoint::GetWishlistItemCount { steam_id: id }.url(self)?;
+
+        let res: WishlistCount = self.get_response(url).await?;
+
+        Ok(res.visibility())
+    }
+
+    /// Returns wishlist of given player, with store details of each app.
+    ///
+    /// At most `concurrency` store requests are made at once. Order of the wishlist is kept.
+    /// Failed store requests, e.g. because of store rate limit, are kept in the entries of the apps.
+    /// Private wishlists are [Visibility::Private].
+    ///
+    /// # Examples
+    /// ```rust
+    /// use steam_api_client::{entity::Visibility, SteamClient};
+    ///
+    /// # tokio_test::block_on(async {
+    /// let client = SteamClient::new();
+    /// match client.get_wishlist_with_details("playerId", Some("us"), 4).await {
+    ///     Ok(Visibility::Public(entries)) => {/*List of wishlisted apps with prices or errors*/},
+    ///     Ok(Visibility::Private) => {/*Wishlist is private*/},
+    ///     Err(e) => {/*Error*/}
+    /// }
+    /// # });
+    /// ```
+    #[tracing::instrument(skip(self))]
+    pub async fn get_wishlist_with_details(
+        &self,
+        id: &str,
+        cc: Option<&str>,
+        concurrency: usize,
+    ) -> Result<Visibility<Vec<WishlistEntry>>, SasError> {
+        let items = match self.get_wishlist(id).await? {
+            Visibility::Public(items) => items,
+            Visibility::Private => return Ok(Visibility::Private),
+        };
+
+        let entries = stream::iter(items)
+            .map(|item| async move {
+                let details = self.get_app_details(item.appid, cc, None).await;
+                WishlistEntry { item, details }
+            })
+            .buffered(concurrency.max(1))
+            .collect()
+            .await;
+
+        Ok(Visibility::Public(entries))
+    }
+}
@@ -0,0 +1,99 @@
+//! Stand-in Steam host shared by integration tests.
+
+#![allow(dead_code)]
+
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpListener,
+    task::JoinHandle,
+};
+
+/// Response sent by the stand-in host.
+pub struct Reply {
+    status: u16,
+    headers: Vec<(&'static str, String)>,
+    body: Vec<u8>,
+}
+
+impl Reply {
+    /// Response with given status and body.
+    pub fn new(status: u16, body: impl Into<Vec<u8>>) -> Self {
+        Self {
+            status,
+            headers: Vec::new(),
+            body: body.into(),
+        }
+    }
+
+    /// Successful response with given body.
+    pub fn ok(body: impl Into<Vec<u8>>) -> Self {
+        Self::new(200, body)
+    }
+
+    /// Adds given header to the response.
+    pub fn header(mut self, name: &'static str, value: impl Into<String>) -> Self {
+        self.headers.push((name, value.into()));
+        self
+    }
+}
+
+/// Starts stand-in host answering one request with each reply in order, returns its url and the requests.
+pub async fn stand_in(replies: Vec<Reply>) -> (String, JoinHandle<Vec<String>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+
+    let requests = tokio::spawn(async move {
+        let mut requests = Vec::new();
+
+        for reply in replies {
+            let (mut socket, _) = listener.accept().await.unwrap();
+
+            let mut request = Vec::new();
+            let mut buf = [0; 4096];
+            loop {
+                let read = socket.read(&mut buf).await.unwrap();
+                request.extend_from_slice(&buf[..read]);
+                if read == 0 || is_complete(&request) {
+                    break;
+                }
+            }
+
+            let mut head = format!(
+                "HTTP/1.1 {} Stand-in\r\ncontent-length: {}\r\nconnection: close\r\n",
+                reply.status,
+                reply.body.len()
+            );
+            for (name, value) in &reply.headers {
+                head.push_str(&format!("{}: {}\r\n", name, value));
+            }
+            head.push_str("\r\n");
+
+            socket.write_all(head.as_bytes()).await.unwrap();
+            socket.write_all(&reply.body).await.unwrap();
+
+            requests.push(String::from_utf8(request).unwrap());
+        }
+
+        requests
+    });
+
+    (url, requests)
+}
+
+/// Returns `true` once headers and the whole body announced by `content-length` are read.
+fn is_complete(request: &[u8]) -> bool {
+    let request = String::from_utf8_lossy(request);
+    let (head, body) = match request.split_once("\r\n\r\n") {
+        Some(parts) => parts,
+        None => return false,
+    };
+
+    let length = head
+        .lines()
+        .filter_map(|line| line.split_once(':'))
+        .find(|(name, _)| name.eq_ignore_ascii_case("content-length"))
+        .and_then(|(_, value)| value.trim().parse().ok())
+        .unwrap_or(0);
+
+    body.len() >= length
+}
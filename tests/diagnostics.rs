@@ -0,0 +1,55 @@
+mod common;
+
+use common::{stand_in, Reply};
+use steam_api_client::{Host, SasError, SteamClient};
+
+fn client(host: &str) -> SteamClient {
+    let mut client = SteamClient::with_api_key("SECRET".to_owned());
+    client.set_host(Host::Api, host);
+    client
+}
+
+#[tokio::test]
+async fn attaches_diagnostics_to_status_errors() {
+    let (host, _requests) = stand_in(vec![Reply::new(502, "<html>Bad Gateway</html>").header("x-request", "1")]).await;
+    let mut client = client(&host);
+    client.set_diagnostics(true);
+
+    let error = client.get_player_info("76561197960287930").await.unwrap_err();
+
+    assert!(matches!(error, SasError::ServerError { .. }));
+    let diagnostics = error.diagnostics().unwrap();
+    assert_eq!(diagnostics.status.as_u16(), 502);
+    assert_eq!(diagnostics.body, "<html>Bad Gateway</html>");
+    assert_eq!(diagnostics.headers["x-request"], "1");
+    assert!(diagnostics.url.contains("/ISteamUser/GetPlayerSummaries/"));
+    assert!(!diagnostics.url.contains("SECRET"));
+    assert!(diagnostics.dump_path.is_none());
+}
+
+#[tokio::test]
+async fn leaves_out_diagnostics_unless_enabled() {
+    let (host, _requests) = stand_in(vec![Reply::new(502, "<html>Bad Gateway</html>")]).await;
+
+    let error = client(&host).get_player_info("76561197960287930").await.unwrap_err();
+
+    assert!(error.diagnostics().is_none());
+}
+
+#[tokio::test]
+async fn dumps_undecodable_responses() {
+    let dir = std::env::temp_dir().join(format!("steam-api-client-dumps-{}", std::process::id()));
+    let (host, _requests) = stand_in(vec![Reply::ok("{\"response\":")]).await;
+    let mut client = client(&host);
+    client.set_diagnostics_dir(&dir);
+
+    let error = client.get_player_info("76561197960287930").await.unwrap_err();
+
+    assert!(matches!(error, SasError::Decode { .. }));
+    let dump_path = error.diagnostics().and_then(|d| d.dump_path.clone()).unwrap();
+    let dump = std::fs::read_to_string(&dump_path).unwrap();
+    assert!(dump.contains("key=REDACTED"));
+    assert!(dump.ends_with("{\"response\":"));
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
@@ -0,0 +1,195 @@
+mod common;
+
+use std::{
+    fs::File,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime},
+};
+
+use common::{stand_in, Reply};
+use steam_api_client::{
+    entity::{AchievementSchema, ImageSource},
+    SteamClient,
+};
+
+/// Empty cache directory unique to the test.
+fn cache_dir(test: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("steam-api-client-cache-{}-{}", test, std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    dir
+}
+
+fn client(dir: &Path, max_size: u64) -> SteamClient {
+    let mut client = SteamClient::new();
+    client.set_image_cache(dir, max_size);
+    client
+}
+
+/// PNG signature followed by given marker, 100 bytes.
+fn png(marker: u8) -> Vec<u8> {
+    let mut data = b"\x89PNG\r\n\x1a\n".to_vec();
+    data.resize(100, marker);
+    data
+}
+
+fn image(marker: u8, cache_control: &str) -> Reply {
+    Reply::ok(png(marker))
+        .header("content-type", "image/png")
+        .header("cache-control", cache_control)
+}
+
+fn achievement(host: &str, name: &str) -> AchievementSchema {
+    serde_json::from_value(serde_json::json!({
+        "name": name,
+        "displayName": name,
+        "hidden": 0,
+        "icon": format!("{}/{}.png", host, name),
+        "icongray": format!("{}/{}_gray.png", host, name),
+    }))
+    .unwrap()
+}
+
+async fn download(client: &SteamClient, achievement: &AchievementSchema) -> Vec<u8> {
+    let source = ImageSource::Achievement {
+        achievement,
+        achieved: true,
+    };
+
+    client.download_image(source).await.unwrap().unwrap().bytes.to_vec()
+}
+
+/// Urls of cached entries, read from their metadata.
+fn cached_urls(dir: &Path) -> Vec<String> {
+    let mut urls: Vec<String> = std::fs::read_dir(dir)
+        .unwrap()
+        .map(|file| file.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "meta"))
+        .map(|path| {
+            let meta: serde_json::Value = serde_json::from_slice(&std::fs::read(path).unwrap()).unwrap();
+            meta["url"].as_str().unwrap().to_owned()
+        })
+        .collect();
+    urls.sort();
+    urls
+}
+
+#[tokio::test]
+async fn uses_fresh_images_without_request() {
+    let dir = cache_dir("fresh");
+    let (host, _requests) = stand_in(vec![image(1, "max-age=3600"), image(2, "max-age=3600")]).await;
+    let client = client(&dir, 1024);
+    let icon = achievement(&host, "a");
+
+    assert_eq!(download(&client, &icon).await, png(1));
+    assert_eq!(download(&client, &icon).await, png(1));
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[tokio::test]
+async fn revalidates_stale_images() {
+    let dir = cache_dir("revalidate");
+    let (host, requests) = stand_in(vec![
+        image(1, "max-age=0").header("etag", "\"v1\""),
+        Reply::new(304, "").header("cache-control", "max-age=3600"),
+    ])
+    .await;
+    let client = client(&dir, 1024);
+    let icon = achievement(&host, "a");
+
+    assert_eq!(download(&client, &icon).await, png(1));
+    assert_eq!(download(&client, &icon).await, png(1));
+
+    let requests = requests.await.unwrap();
+    assert!(requests[1].to_lowercase().contains("if-none-match: \"v1\""));
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[tokio::test]
+async fn falls_back_to_stale_images() {
+    let dir = cache_dir("fallback");
+    let (host, requests) = stand_in(vec![image(1, "no-cache"), Reply::new(503, "")]).await;
+    let client = client(&dir, 1024);
+    let icon = achievement(&host, "a");
+
+    assert_eq!(download(&client, &icon).await, png(1));
+    // server error
+    assert_eq!(download(&client, &icon).await, png(1));
+
+    // nothing listens on the host anymore
+    requests.await.unwrap();
+    assert_eq!(download(&client, &icon).await, png(1));
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[tokio::test]
+async fn downloads_again_for_corrupt_metadata() {
+    let dir = cache_dir("corrupt");
+    let (host, _requests) = stand_in(vec![image(1, "max-age=3600"), image(2, "max-age=3600")]).await;
+    let client = client(&dir, 1024);
+    let icon = achievement(&host, "a");
+
+    assert_eq!(download(&client, &icon).await, png(1));
+
+    for file in std::fs::read_dir(&dir).unwrap() {
+        let path = file.unwrap().path();
+        if path.extension().is_some_and(|ext| ext == "meta") {
+            std::fs::write(path, "{not json").unwrap();
+        }
+    }
+
+    assert_eq!(download(&client, &icon).await, png(2));
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[tokio::test]
+async fn evicts_least_recently_used_images() {
+    let dir = cache_dir("evict");
+    let (host, _requests) = stand_in(vec![
+        image(1, "max-age=3600"),
+        image(2, "max-age=3600"),
+        image(3, "max-age=3600"),
+    ])
+    .await;
+    // room for two images
+    let client = client(&dir, 250);
+    let (a, b, c) = (achievement(&host, "a"), achievement(&host, "b"), achievement(&host, "c"));
+
+    for icon in [&a, &b, &a, &c] {
+        download(&client, icon).await;
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+
+    assert_eq!(cached_urls(&dir), [a.icon.clone(), c.icon.clone()]);
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[tokio::test]
+async fn removes_leftovers_of_interrupted_writes() {
+    let dir = cache_dir("leftovers");
+    std::fs::create_dir_all(&dir).unwrap();
+
+    let hour_ago = SystemTime::now() - Duration::from_secs(60 * 60);
+    let leftover = |name: &str, modified: SystemTime| {
+        let path = dir.join(name);
+        File::create(&path).unwrap().set_modified(modified).unwrap();
+        path
+    };
+    let old_tmp = leftover("0123.meta.1-0.tmp", hour_ago);
+    let old_data = leftover("4567", hour_ago);
+    let new_tmp = leftover("89ab.1-1.tmp", SystemTime::now());
+
+    let (host, _requests) = stand_in(vec![image(1, "max-age=3600")]).await;
+    let client = client(&dir, 1024);
+    download(&client, &achievement(&host, "a")).await;
+
+    assert!(!old_tmp.exists());
+    assert!(!old_data.exists());
+    assert!(new_tmp.exists(), "writes in progress are kept");
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
@@ -0,0 +1,98 @@
+mod common;
+
+use std::collections::HashMap;
+
+use common::{stand_in, Reply};
+use steam_api_client::{Host, SasError, SteamClient, SteamId};
+
+const RETURN_TO: &str = "https://example.com/auth/callback";
+const SIGNED: &str = "signed,op_endpoint,claimed_id,identity,return_to,response_nonce,assoc_handle";
+
+fn client(host: &str) -> SteamClient {
+    let mut client = SteamClient::with_api_key("key".to_owned());
+    client.set_host(Host::Community, host);
+    client
+}
+
+/// Callback parameters as Steam sends them after successful login.
+fn callback_params(host: &str) -> HashMap<String, String> {
+    let claimed_id = format!("{}/openid/id/76561197960287930", host);
+
+    [
+        ("openid.ns", "http://specs.openid.net/auth/2.0"),
+        ("openid.mode", "id_res"),
+        ("openid.op_endpoint", &format!("{}/openid/login", host)),
+        ("openid.claimed_id", &claimed_id),
+        ("openid.identity", &claimed_id),
+        ("openid.return_to", RETURN_TO),
+        ("openid.response_nonce", "2024-01-01T00:00:00Zabc"),
+        ("openid.assoc_handle", "1234567890"),
+        ("openid.signed", SIGNED),
+        ("openid.sig", "signature"),
+    ]
+    .into_iter()
+    .map(|(name, value)| (name.to_owned(), value.to_owned()))
+    .collect()
+}
+
+#[tokio::test]
+async fn login_url_points_to_host() {
+    let client = client("http://127.0.0.1:1");
+
+    let url = client.openid_login_url("https://example.com", RETURN_TO).unwrap();
+
+    assert!(url.starts_with("http://127.0.0.1:1/openid/login?"));
+    assert!(url.contains("openid.mode=checkid_setup"));
+}
+
+#[tokio::test]
+async fn verifies_login() {
+    let (host, request) = stand_in(vec![Reply::ok("ns:http://specs.openid.net/auth/2.0\nis_valid:true\n")]).await;
+    let client = client(&host);
+
+    let id = client.verify_openid_login(RETURN_TO, &callback_params(&host)).await.unwrap();
+
+    assert_eq!(id, SteamId(76561197960287930));
+
+    let request = request.await.unwrap().remove(0);
+    assert!(request.starts_with("POST /openid/login "));
+    assert!(request.contains("openid.mode=check_authentication"));
+    assert!(request.contains("openid.sig=signature"));
+}
+
+#[tokio::test]
+async fn fails_when_steam_rejects_login() {
+    let (host, _request) = stand_in(vec![Reply::ok("ns:http://specs.openid.net/auth/2.0\nis_valid:false\n")]).await;
+    let client = client(&host);
+
+    let res = client.verify_openid_login(RETURN_TO, &callback_params(&host)).await;
+
+    assert!(matches!(res, Err(SasError::OpenIdError(_))));
+}
+
+#[tokio::test]
+async fn fails_for_unsigned_fields() {
+    let host = "http://127.0.0.1:1";
+    let client = client(host);
+
+    for field in ["claimed_id", "identity", "return_to", "op_endpoint", "response_nonce", "assoc_handle"] {
+        let mut params = callback_params(host);
+        let signed: Vec<&str> = SIGNED.split(',').filter(|name| *name != field).collect();
+        params.insert("openid.signed".to_owned(), signed.join(","));
+
+        // fails before contacting Steam, nothing listens on the host
+        let res = client.verify_openid_login(RETURN_TO, &params).await;
+
+        assert!(matches!(res, Err(SasError::OpenIdError(_))), "{} is not signed", field);
+    }
+}
+
+#[tokio::test]
+async fn fails_for_other_return_url() {
+    let host = "http://127.0.0.1:1";
+    let client = client(host);
+
+    let res = client.verify_openid_login("https://other.example.com", &callback_params(host)).await;
+
+    assert!(matches!(res, Err(SasError::OpenIdError(_))));
+}
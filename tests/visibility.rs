@@ -0,0 +1,103 @@
+mod common;
+
+use common::{stand_in, Reply};
+use steam_api_client::{entity::Visibility, AppId, Host, SasError, SteamClient};
+
+fn client(host: &str) -> SteamClient {
+    let mut client = SteamClient::with_api_key("key".to_owned());
+    client.set_host(Host::Api, host);
+    client
+}
+
+async fn achievements(reply: Reply) -> Result<Visibility<Option<usize>>, SasError> {
+    let (host, _requests) = stand_in(vec![reply]).await;
+
+    let res = client(&host).get_achievements_for_game("76561197960287930", AppId(400), None).await?;
+
+    Ok(res.map(|achievements| achievements.map(|achievements| achievements.len())))
+}
+
+#[tokio::test]
+async fn reads_achievements() {
+    let body = r#"{"playerstats":{"steamID":"76561197960287930","gameName":"Portal","achievements":[
+        {"apiname":"PORTAL_TRANSMISSION_RECEIVED","achieved":1,"unlocktime":1200000000}],"success":true}}"#;
+
+    let res = achievements(Reply::ok(body)).await.unwrap();
+
+    assert!(matches!(res, Visibility::Public(Some(1))));
+}
+
+#[tokio::test]
+async fn reads_games_without_achievements() {
+    let body = r#"{"playerstats":{"steamID":"76561197960287930","gameName":"Portal","success":true}}"#;
+    let res = achievements(Reply::ok(body)).await.unwrap();
+    assert!(matches!(res, Visibility::Public(None)));
+
+    let body = r#"{"playerstats":{"error":"Requested app has no stats","success":false}}"#;
+    let res = achievements(Reply::new(400, body)).await.unwrap();
+    assert!(matches!(res, Visibility::Public(None)));
+}
+
+#[tokio::test]
+async fn reads_private_achievements() {
+    let body = r#"{"playerstats":{"error":"Profile is not public","success":false}}"#;
+
+    let res = achievements(Reply::new(403, body)).await.unwrap();
+
+    assert!(matches!(res, Visibility::Private));
+}
+
+#[tokio::test]
+async fn maps_achievement_failures_by_status() {
+    let res = achievements(Reply::new(403, "<html>Forbidden</html>")).await;
+    assert!(matches!(res, Err(SasError::Unauthorized { .. })));
+
+    let res = achievements(Reply::new(401, "<html>Unauthorized</html>")).await;
+    assert!(matches!(res, Err(SasError::Unauthorized { .. })));
+
+    let res = achievements(Reply::new(404, "<html>Not Found</html>")).await;
+    assert!(matches!(res, Err(SasError::NotFound { .. })));
+}
+
+#[tokio::test]
+async fn tells_private_wishlists_from_empty() {
+    let (host, _requests) = stand_in(vec![
+        Reply::ok(r#"{"response":{"items":[]}}"#),
+        Reply::ok(r#"{"response":{}}"#),
+        Reply::ok(r#"{"response":{"count":0}}"#),
+        Reply::ok(r#"{"response":{}}"#),
+    ])
+    .await;
+    let client = client(&host);
+
+    let res = client.get_wishlist("76561197960287930").await.unwrap();
+    assert!(matches!(res, Visibility::Public(items) if items.is_empty()));
+
+    let res = client.get_wishlist("76561197960287930").await.unwrap();
+    assert!(res.is_private());
+
+    let res = client.get_wishlist_item_count("76561197960287930").await.unwrap();
+    assert!(matches!(res, Visibility::Public(0)));
+
+    let res = client.get_wishlist_item_count("76561197960287930").await.unwrap();
+    assert!(res.is_private());
+}
+
+#[tokio::test]
+async fn tells_private_profiles_from_rejected_keys() {
+    let (host, _requests) = stand_in(vec![
+        Reply::new(403, "<html><body>This profile is private.</body></html>"),
+        Reply::new(
+            403,
+            "<html><body>Access is denied. Retrying will not help. Please verify your <pre>key=</pre> parameter.</body></html>",
+        ),
+    ])
+    .await;
+    let client = client(&host);
+
+    let res = client.get_player_info("76561197960287930").await;
+    assert!(matches!(res, Err(SasError::PrivateProfile { .. })));
+
+    let res = client.get_player_info("76561197960287930").await;
+    assert!(matches!(res, Err(SasError::Unauthorized { .. })));
+}